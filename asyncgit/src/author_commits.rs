@@ -0,0 +1,82 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{commits_by_author, CommitId, RepoPath},
+	AsyncGitNotification,
+};
+
+use std::sync::{Arc, Mutex};
+
+enum JobState {
+	Request(Vec<CommitId>),
+	Response(Result<Vec<CommitId>>),
+}
+
+/// finds the commits of a log authored by someone,
+/// see `sync::commits_by_author`
+#[derive(Clone)]
+pub struct AsyncAuthorCommitsJob {
+	state: Arc<Mutex<Option<JobState>>>,
+	repo: RepoPath,
+	author: String,
+}
+
+///
+impl AsyncAuthorCommitsJob {
+	/// looks for commits by `author` among `ids`
+	pub fn new(
+		repo: RepoPath,
+		ids: Vec<CommitId>,
+		author: &str,
+	) -> Self {
+		Self {
+			repo,
+			state: Arc::new(Mutex::new(Some(JobState::Request(ids)))),
+			author: author.to_string(),
+		}
+	}
+
+	/// see `new`
+	pub fn author(&self) -> &str {
+		&self.author
+	}
+
+	///
+	pub fn result(&self) -> Option<Result<Vec<CommitId>>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request(_) => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncAuthorCommitsJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request(ids) => JobState::Response(
+					commits_by_author(&self.repo, &ids, &self.author),
+				),
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::AuthorCommits)
+	}
+}
@@ -1,6 +1,6 @@
 use crate::{
 	error::Result,
-	sync::{self, CommitId, RepoPath},
+	sync::{self, CommitId, DiffParent, RepoPath},
 	AsyncGitNotification, StatusItem,
 };
 use crossbeam_channel::Sender;
@@ -19,11 +19,17 @@ pub struct CommitFilesParams {
 	pub id: CommitId,
 	///
 	pub other: Option<CommitId>,
+	/// what `id` is compared to if there is no `other`
+	pub parent: DiffParent,
 }
 
 impl From<CommitId> for CommitFilesParams {
 	fn from(id: CommitId) -> Self {
-		Self { id, other: None }
+		Self {
+			id,
+			other: None,
+			parent: DiffParent::default(),
+		}
 	}
 }
 
@@ -32,6 +38,7 @@ impl From<(CommitId, CommitId)> for CommitFilesParams {
 		Self {
 			id,
 			other: Some(other),
+			parent: DiffParent::default(),
 		}
 	}
 }
@@ -119,11 +126,19 @@ impl AsyncCommitFiles {
 			Mutex<Option<Request<CommitFilesParams, ResultType>>>,
 		>,
 	) -> Result<()> {
-		let res = sync::get_commit_files(
-			repo_path,
-			params.id,
-			params.other,
-		)?;
+		let res = if params.other.is_some() {
+			sync::get_commit_files(
+				repo_path,
+				params.id,
+				params.other,
+			)?
+		} else {
+			sync::get_commit_files_against(
+				repo_path,
+				params.id,
+				params.parent,
+			)?
+		};
 
 		log::trace!("get_commit_files: {:?} ({})", params, res.len());
 
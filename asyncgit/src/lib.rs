@@ -23,12 +23,14 @@
 // #![deny(clippy::expect_used)]
 
 pub mod asyncjob;
+pub mod author_commits;
 mod blame;
 pub mod cached;
 mod commit_files;
 mod diff;
 mod error;
 mod fetch_job;
+pub mod line_stats;
 mod progress;
 mod pull;
 mod push;
@@ -51,7 +53,7 @@ pub use crate::{
 	push::{AsyncPush, PushRequest},
 	push_tags::{AsyncPushTags, PushTagsRequest},
 	remote_progress::{RemoteProgress, RemoteProgressState},
-	revlog::{AsyncLog, FetchStatus},
+	revlog::{AsyncLog, FetchStatus, LogSleep, LogStatus},
 	status::{AsyncStatus, StatusParams},
 	sync::{
 		diff::{DiffLine, DiffLineType, FileDiff},
@@ -95,6 +97,10 @@ pub enum AsyncGitNotification {
 	RemoteTags,
 	///
 	Fetch,
+	///
+	AuthorCommits,
+	///
+	LineStats,
 }
 
 /// helper function to calculate the hash of an arbitrary type that implements the `Hash` trait
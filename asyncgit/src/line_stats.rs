@@ -0,0 +1,90 @@
+//!
+
+use crate::{
+	asyncjob::{AsyncJob, RunParams},
+	error::Result,
+	sync::{
+		commit_line_stats, CommitId, CommitLineStats, DiffParent,
+		RepoPath,
+	},
+	AsyncGitNotification,
+};
+
+use std::sync::{Arc, Mutex};
+
+enum JobState {
+	Request,
+	Response(Result<CommitLineStats>),
+}
+
+/// computes the diffstat of a commit, see `sync::commit_line_stats`
+#[derive(Clone)]
+pub struct AsyncLineStatsJob {
+	state: Arc<Mutex<Option<JobState>>>,
+	repo: RepoPath,
+	id: CommitId,
+	parent: DiffParent,
+}
+
+///
+impl AsyncLineStatsJob {
+	/// stats of `id` against `parent`
+	pub fn new(
+		repo: RepoPath,
+		id: CommitId,
+		parent: DiffParent,
+	) -> Self {
+		Self {
+			repo,
+			state: Arc::new(Mutex::new(Some(JobState::Request))),
+			id,
+			parent,
+		}
+	}
+
+	/// see `new`
+	pub const fn params(&self) -> (CommitId, DiffParent) {
+		(self.id, self.parent)
+	}
+
+	///
+	pub fn result(&self) -> Option<Result<CommitLineStats>> {
+		if let Ok(mut state) = self.state.lock() {
+			if let Some(state) = state.take() {
+				return match state {
+					JobState::Request => None,
+					JobState::Response(result) => Some(result),
+				};
+			}
+		}
+
+		None
+	}
+}
+
+impl AsyncJob for AsyncLineStatsJob {
+	type Notification = AsyncGitNotification;
+	type Progress = ();
+
+	fn run(
+		&mut self,
+		_params: RunParams<Self::Notification, Self::Progress>,
+	) -> Result<Self::Notification> {
+		if let Ok(mut state) = self.state.lock() {
+			*state = state.take().map(|state| match state {
+				JobState::Request => {
+					JobState::Response(commit_line_stats(
+						&self.repo,
+						self.id,
+						self.parent,
+					))
+				}
+				JobState::Response(result) => {
+					JobState::Response(result)
+				}
+			});
+		}
+
+		Ok(AsyncGitNotification::LineStats)
+	}
+}
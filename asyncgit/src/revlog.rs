@@ -25,6 +25,46 @@ pub enum FetchStatus {
 	Started,
 }
 
+/// progress of the current walk
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LogStatus {
+	/// commits loaded so far
+	pub loaded: usize,
+	/// whether the walk is still running
+	pub pending: bool,
+}
+
+impl LogStatus {
+	/// total number of commits, only known once the walk is done
+	pub const fn total(&self) -> Option<usize> {
+		if self.pending {
+			None
+		} else {
+			Some(self.loaded)
+		}
+	}
+}
+
+/// pause between two batches of a walk.
+/// shorter pauses load big histories faster but leave the
+/// ui less cpu time to stay responsive while doing so
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogSleep {
+	/// while the log is visible
+	pub foreground: Duration,
+	/// while the log is in the background (see `set_background`)
+	pub background: Duration,
+}
+
+impl Default for LogSleep {
+	fn default() -> Self {
+		Self {
+			foreground: Duration::from_millis(2),
+			background: Duration::from_millis(1000),
+		}
+	}
+}
+
 ///
 pub struct AsyncLog {
 	current: Arc<Mutex<Vec<CommitId>>>,
@@ -33,12 +73,17 @@ pub struct AsyncLog {
 	pending: Arc<AtomicBool>,
 	background: Arc<AtomicBool>,
 	filter: Option<LogWalkerFilter>,
+	first_parent: bool,
+	topo_order: bool,
+	no_merges: bool,
+	max_commits: Option<usize>,
+	start_ref: Option<String>,
+	since: Option<i64>,
+	sleep: LogSleep,
 	repo: RepoPath,
 }
 
 static LIMIT_COUNT: usize = 3000;
-static SLEEP_FOREGROUND: Duration = Duration::from_millis(2);
-static SLEEP_BACKGROUND: Duration = Duration::from_millis(1000);
 
 impl AsyncLog {
 	///
@@ -55,6 +100,13 @@ impl AsyncLog {
 			pending: Arc::new(AtomicBool::new(false)),
 			background: Arc::new(AtomicBool::new(false)),
 			filter,
+			first_parent: false,
+			topo_order: false,
+			no_merges: false,
+			max_commits: None,
+			start_ref: None,
+			since: None,
+			sleep: LogSleep::default(),
 		}
 	}
 
@@ -90,24 +142,180 @@ impl AsyncLog {
 		self.pending.load(Ordering::Relaxed)
 	}
 
+	/// `count` and `is_pending` in one
+	pub fn status(&self) -> Result<LogStatus> {
+		Ok(LogStatus {
+			loaded: self.count()?,
+			pending: self.is_pending(),
+		})
+	}
+
 	///
 	pub fn set_background(&mut self) {
 		self.background.store(true, Ordering::Relaxed);
 	}
 
+	/// see `set_first_parent`
+	pub const fn first_parent(&self) -> bool {
+		self.first_parent
+	}
+
+	/// only walk the first parent of merge commits
+	/// (like `git log --first-parent`), the next `fetch` starts a new walk
+	pub fn set_first_parent(
+		&mut self,
+		first_parent: bool,
+	) -> Result<()> {
+		if self.first_parent != first_parent {
+			self.first_parent = first_parent;
+			self.reset()?;
+		}
+
+		Ok(())
+	}
+
+	/// see `set_topo_order`
+	pub const fn topo_order(&self) -> bool {
+		self.topo_order
+	}
+
+	/// never list a commit before its children
+	/// (like `git log --topo-order`) instead of ordering by date,
+	/// the next `fetch` starts a new walk
+	pub fn set_topo_order(&mut self, topo_order: bool) -> Result<()> {
+		if self.topo_order != topo_order {
+			self.topo_order = topo_order;
+			self.reset()?;
+		}
+
+		Ok(())
+	}
+
+	/// see `set_no_merges`
+	pub const fn no_merges(&self) -> bool {
+		self.no_merges
+	}
+
+	/// leave out merge commits (like `git log --no-merges`),
+	/// the next `fetch` starts a new walk
+	pub fn set_no_merges(&mut self, no_merges: bool) -> Result<()> {
+		if self.no_merges != no_merges {
+			self.no_merges = no_merges;
+			self.reset()?;
+		}
+
+		Ok(())
+	}
+
+	/// see `set_max_commits`
+	pub const fn max_commits(&self) -> Option<usize> {
+		self.max_commits
+	}
+
+	/// stop walking after `max_commits` commits (`None` loads all).
+	/// ignored while a filter is set, the next `fetch` starts a new walk
+	pub fn set_max_commits(
+		&mut self,
+		max_commits: Option<usize>,
+	) -> Result<()> {
+		if self.max_commits != max_commits {
+			self.max_commits = max_commits;
+			self.reset()?;
+		}
+
+		Ok(())
+	}
+
+	/// see `set_start_ref`
+	pub fn start_ref(&self) -> Option<&str> {
+		self.start_ref.as_deref()
+	}
+
+	/// walk the commits reachable from `start_ref` instead of `HEAD`
+	/// (`None` goes back to `HEAD`), errors if the ref does not exist.
+	/// the next `fetch` starts a new walk
+	pub fn set_start_ref(
+		&mut self,
+		start_ref: Option<String>,
+	) -> Result<()> {
+		if self.start_ref != start_ref {
+			if let Some(start_ref) = &start_ref {
+				resolve_ref(&self.repo, start_ref)?;
+			}
+			self.start_ref = start_ref;
+			self.reset()?;
+		}
+
+		Ok(())
+	}
+
+	/// see `set_since`
+	pub const fn since(&self) -> Option<i64> {
+		self.since
+	}
+
+	/// stop walking once commits get older than `since`
+	/// (seconds since epoch, `None` loads all).
+	/// the next `fetch` starts a new walk
+	pub fn set_since(&mut self, since: Option<i64>) -> Result<()> {
+		if self.since != since {
+			self.since = since;
+			self.reset()?;
+		}
+
+		Ok(())
+	}
+
+	/// replaces the filter, the next `fetch` starts a new walk
+	pub fn set_filter(
+		&mut self,
+		filter: Option<LogWalkerFilter>,
+	) -> Result<()> {
+		self.filter = filter;
+		self.reset()
+	}
+
+	/// pause between batches of the following walks
+	pub fn set_sleep(&mut self, sleep: LogSleep) {
+		self.sleep = sleep;
+	}
+
+	/// discards the current walk even if it is still pending
+	/// so that the next `fetch` starts from scratch
+	pub fn reset(&mut self) -> Result<()> {
+		// a walk still running keeps its own (now orphaned) state
+		// and stops on its own once it notices that
+		self.current = Arc::new(Mutex::new(Vec::new()));
+		self.pending = Arc::new(AtomicBool::new(false));
+		*self.current_head.lock()? = None;
+		Ok(())
+	}
+
 	///
 	fn current_head(&self) -> Result<Option<CommitId>> {
 		Ok(*self.current_head.lock()?)
 	}
 
-	///
-	fn head_changed(&self) -> Result<bool> {
-		if let Ok(head) = repo(&self.repo)?.head() {
-			return Ok(
-				head.target() != self.current_head()?.map(Into::into)
-			);
+	/// commit the walk starts at: `HEAD` or the `start_ref`
+	fn walk_start(&self) -> Result<Option<CommitId>> {
+		if let Some(start_ref) = &self.start_ref {
+			return resolve_ref(&self.repo, start_ref).map(Some);
+		}
+
+		Ok(repo(&self.repo)?
+			.head()
+			.ok()
+			.and_then(|head| head.target())
+			.map(CommitId::new))
+	}
+
+	/// the new start if it moved since the last walk
+	fn start_changed(&self) -> Result<Option<CommitId>> {
+		let start = self.walk_start()?;
+		if start.is_some() && start != self.current_head()? {
+			return Ok(start);
 		}
-		Ok(false)
+		Ok(None)
 	}
 
 	///
@@ -118,9 +326,10 @@ impl AsyncLog {
 			return Ok(FetchStatus::Pending);
 		}
 
-		if !self.head_changed()? {
-			return Ok(FetchStatus::NoChange);
-		}
+		let start = match self.start_changed()? {
+			Some(start) => start,
+			None => return Ok(FetchStatus::NoChange),
+		};
 
 		self.clear()?;
 
@@ -129,14 +338,21 @@ impl AsyncLog {
 		let arc_pending = Arc::clone(&self.pending);
 		let arc_background = Arc::clone(&self.background);
 		let filter = self.filter.clone();
+		let first_parent = self.first_parent;
+		let topo_order = self.topo_order;
+		let no_merges = self.no_merges;
+		let since = self.since;
+		let sleep = self.sleep;
+		let max_commits = if self.filter.is_some() {
+			usize::MAX
+		} else {
+			self.max_commits.unwrap_or(usize::MAX)
+		};
 		let repo_path = self.repo.clone();
 
 		self.pending.store(true, Ordering::Relaxed);
 
-		if let Ok(head) = repo(&self.repo)?.head() {
-			*self.current_head.lock()? =
-				head.target().map(CommitId::new);
-		}
+		*self.current_head.lock()? = Some(start);
 
 		rayon_core::spawn(move || {
 			scope_time!("async::revlog");
@@ -146,7 +362,14 @@ impl AsyncLog {
 				&arc_current,
 				&arc_background,
 				&sender,
+				start,
 				filter,
+				first_parent,
+				topo_order,
+				no_merges,
+				since,
+				max_commits,
+				sleep,
 			)
 			.expect("failed to fetch");
 
@@ -158,36 +381,59 @@ impl AsyncLog {
 		Ok(FetchStatus::Started)
 	}
 
+	#[allow(clippy::too_many_arguments)]
 	fn fetch_helper(
 		repo_path: &RepoPath,
 		arc_current: &Arc<Mutex<Vec<CommitId>>>,
 		arc_background: &Arc<AtomicBool>,
 		sender: &Sender<AsyncGitNotification>,
+		start: CommitId,
 		filter: Option<LogWalkerFilter>,
+		first_parent: bool,
+		topo_order: bool,
+		no_merges: bool,
+		since: Option<i64>,
+		max_commits: usize,
+		sleep: LogSleep,
 	) -> Result<()> {
 		let mut entries = Vec::with_capacity(LIMIT_COUNT);
 		let r = repo(repo_path)?;
-		let mut walker =
-			LogWalker::new(&r, LIMIT_COUNT)?.filter(filter);
+		let mut walker = LogWalker::new_at(&r, start, LIMIT_COUNT)?
+			.filter(filter)
+			.first_parent(first_parent)
+			.topo_order(topo_order)
+			.no_merges(no_merges)
+			.since(since);
 		loop {
 			entries.clear();
-			let res_is_err = walker.read(&mut entries).is_err();
+			let read = walker.read(&mut entries);
+			let res_is_err = read.is_err();
+			// a filter can reject whole batches,
+			// only an empty read means the walk is done
+			let walk_done = read.map_or(true, |count| count == 0);
 
-			if !res_is_err {
+			let limit_reached = if res_is_err {
+				false
+			} else {
 				let mut current = arc_current.lock()?;
 				current.extend(entries.iter());
-			}
+				current.truncate(max_commits);
+				current.len() >= max_commits
+			};
+
+			// nobody but us holds on to the results: we were `reset`
+			let discarded = Arc::strong_count(arc_current) == 1;
 
-			if res_is_err || entries.len() <= 1 {
+			if walk_done || discarded || limit_reached {
 				break;
 			}
 			Self::notify(sender);
 
 			let sleep_duration =
 				if arc_background.load(Ordering::Relaxed) {
-					SLEEP_BACKGROUND
+					sleep.background
 				} else {
-					SLEEP_FOREGROUND
+					sleep.foreground
 				};
 			thread::sleep(sleep_duration);
 		}
@@ -207,3 +453,161 @@ impl AsyncLog {
 			.expect("error sending");
 	}
 }
+
+fn resolve_ref(repo_path: &RepoPath, name: &str) -> Result<CommitId> {
+	let repo = repo(repo_path)?;
+	let commit = repo.revparse_single(name)?.peel_to_commit()?;
+	Ok(commit.id().into())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		commit_time_matches, non_empty_commits,
+		tests::repo_init_empty, unresolved_conflict_merges,
+	};
+	use git2::{Repository, Signature, Time};
+	use std::convert::TryFrom;
+
+	fn commit_content(
+		repo: &Repository,
+		content: &str,
+		parents: &[CommitId],
+		time: i64,
+	) -> CommitId {
+		let blob = repo.blob(content.as_bytes()).unwrap();
+		let mut builder = repo.treebuilder(None).unwrap();
+		builder.insert("test.txt", blob, 0o100_644).unwrap();
+		let tree = repo.find_tree(builder.write().unwrap()).unwrap();
+
+		let parents = parents
+			.iter()
+			.map(|id| repo.find_commit((*id).into()).unwrap())
+			.collect::<Vec<_>>();
+		let parents = parents.iter().collect::<Vec<_>>();
+
+		let sig =
+			Signature::new("name", "email", &Time::new(time, 0))
+				.unwrap();
+		repo.commit(None, &sig, &sig, content, &tree, &parents)
+			.unwrap()
+			.into()
+	}
+
+	/// more commits than a single batch of the walk on top of
+	/// `parent`, a minute apart starting at `time`
+	fn bury(
+		repo: &Repository,
+		parent: CommitId,
+		content: &str,
+		time: i64,
+	) -> CommitId {
+		(0..LIMIT_COUNT + 10).fold(parent, |parent, idx| {
+			let time = time + 60 * i64::try_from(idx).unwrap();
+			commit_content(repo, content, &[parent], time)
+		})
+	}
+
+	fn walk(
+		repo_path: &RepoPath,
+		start: CommitId,
+		filter: LogWalkerFilter,
+	) -> Vec<CommitId> {
+		let current = Arc::new(Mutex::new(Vec::new()));
+		// the walk stops once nobody else holds on to the results
+		let _keep = Arc::clone(&current);
+		let (sender, _receiver) = crossbeam_channel::unbounded();
+
+		AsyncLog::fetch_helper(
+			repo_path,
+			&current,
+			&Arc::new(AtomicBool::new(false)),
+			&sender,
+			start,
+			Some(filter),
+			false,
+			false,
+			false,
+			None,
+			usize::MAX,
+			LogSleep {
+				foreground: Duration::from_secs(0),
+				background: Duration::from_secs(0),
+			},
+		)
+		.unwrap();
+
+		let res = current.lock().unwrap().clone();
+		res
+	}
+
+	#[test]
+	fn test_conflicted_merge_below_first_batch() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: RepoPath =
+			root.as_os_str().to_str().unwrap().into();
+
+		let base = commit_content(&repo, "base\n", &[], 100);
+		let ours = commit_content(&repo, "ours\n", &[base], 200);
+		let theirs = commit_content(&repo, "theirs\n", &[base], 300);
+		let unresolved = commit_content(
+			&repo,
+			"<<<<<<< ours\nours\n=======\ntheirs\n>>>>>>> theirs\n",
+			&[ours, theirs],
+			400,
+		);
+		let head = bury(&repo, unresolved, "resolved\n", 1000);
+
+		assert_eq!(
+			walk(&repo_path, head, unresolved_conflict_merges()),
+			vec![unresolved]
+		);
+	}
+
+	#[test]
+	fn test_time_match_below_first_batch() {
+		// sunday 2021-01-03 and the monday after
+		const SUNDAY: i64 = 1_609_675_200;
+		const MONDAY: i64 = 1_609_718_400;
+
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: RepoPath =
+			root.as_os_str().to_str().unwrap().into();
+
+		let weekend = commit_content(&repo, "a\n", &[], SUNDAY);
+		let head = bury(&repo, weekend, "b\n", MONDAY);
+
+		assert_eq!(
+			walk(
+				&repo_path,
+				head,
+				commit_time_matches(|time| time < MONDAY)
+			),
+			vec![weekend]
+		);
+	}
+
+	#[test]
+	fn test_non_empty_below_first_batch() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: RepoPath =
+			root.as_os_str().to_str().unwrap().into();
+
+		let changed = commit_content(&repo, "a\n", &[], 100);
+		// same content: nothing changes after the first one
+		let head = bury(&repo, changed, "a\n", 1000);
+
+		assert_eq!(
+			walk(
+				&repo_path,
+				head,
+				non_empty_commits(repo_path.clone())
+			),
+			vec![changed]
+		);
+	}
+}
@@ -5,7 +5,7 @@ use crate::{
 	error::{Error, Result},
 	sync::{get_commits_info, repository::repo},
 };
-use git2::BlameOptions;
+use git2::{BlameOptions, Blob, Repository};
 use scopetime::scope_time;
 use std::collections::{HashMap, HashSet};
 use std::io::{BufRead, BufReader};
@@ -53,6 +53,51 @@ fn fixup_windows_path(path: &str) -> String {
 	}
 }
 
+/// the file `blame_file` blames, errors if it does not exist
+/// at `commit_id` (e.g. it was deleted) or is binary
+fn blamed_blob<'a>(
+	repo: &'a Repository,
+	file_path: &str,
+	commit_id: CommitId,
+) -> Result<Blob<'a>> {
+	let spec = format!(
+		"{}:{}",
+		commit_id.to_string(),
+		fixup_windows_path(file_path)
+	);
+
+	let object = repo.revparse_single(&spec)?;
+	let blob = repo.find_blob(object.id())?;
+
+	if blob.is_binary() {
+		return Err(Error::NoBlameOnBinaryFile);
+	}
+
+	Ok(blob)
+}
+
+/// errors if `blame_file` would fail right away,
+/// without doing the (expensive) blame itself
+pub fn blame_check(
+	repo_path: &RepoPath,
+	file_path: &str,
+	commit_id: Option<CommitId>,
+) -> Result<()> {
+	scope_time!("blame_check");
+
+	let repo = repo(repo_path)?;
+
+	let commit_id = if let Some(commit_id) = commit_id {
+		commit_id
+	} else {
+		utils::get_head_repo(&repo)?
+	};
+
+	blamed_blob(&repo, file_path, commit_id)?;
+
+	Ok(())
+}
+
 ///
 pub fn blame_file(
 	repo_path: &RepoPath,
@@ -69,18 +114,7 @@ pub fn blame_file(
 		utils::get_head_repo(&repo)?
 	};
 
-	let spec = format!(
-		"{}:{}",
-		commit_id.to_string(),
-		fixup_windows_path(file_path)
-	);
-
-	let object = repo.revparse_single(&spec)?;
-	let blob = repo.find_blob(object.id())?;
-
-	if blob.is_binary() {
-		return Err(Error::NoBlameOnBinaryFile);
-	}
+	let blob = blamed_blob(&repo, file_path, commit_id)?;
 
 	let mut opts = BlameOptions::new();
 	opts.newest_commit(commit_id.into());
@@ -154,7 +188,10 @@ mod tests {
 	use super::*;
 	use crate::{
 		error::Result,
-		sync::{commit, stage_add_file, tests::repo_init_empty},
+		sync::{
+			commit, stage_add_file, stage_addremoved,
+			tests::repo_init_empty,
+		},
 	};
 	use std::{
 		fs::{File, OpenOptions},
@@ -246,6 +283,29 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_blame_check() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(root.join(file_path))?.write_all(b"line 1\n")?;
+		stage_add_file(repo_path, file_path)?;
+		let added = commit(repo_path, "add")?;
+
+		std::fs::remove_file(root.join(file_path))?;
+		stage_addremoved(repo_path, file_path)?;
+		let removed = commit(repo_path, "remove")?;
+
+		assert!(blame_check(repo_path, "foo", Some(added)).is_ok());
+		assert!(blame_check(repo_path, "foo", Some(removed)).is_err());
+		assert!(blame_check(repo_path, "foo", None).is_err());
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_blame_windows_path_dividers() {
 		let file_path = Path::new("bar\\foo");
@@ -78,6 +78,8 @@ pub struct CommitDetails {
 	pub message: Option<CommitMessage>,
 	///
 	pub hash: String,
+	/// empty for a root commit
+	pub parents: Vec<CommitId>,
 }
 
 impl CommitDetails {
@@ -114,6 +116,7 @@ pub fn get_commit_details(
 		committer,
 		message: Some(msg),
 		hash: id.to_string(),
+		parents: commit.parent_ids().map(CommitId::new).collect(),
 	};
 
 	Ok(details)
@@ -158,6 +161,30 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_parents() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let first = commit(repo_path, "first").unwrap();
+		let second = commit(repo_path, "second").unwrap();
+
+		assert!(get_commit_details(repo_path, first)?
+			.parents
+			.is_empty());
+		assert_eq!(
+			get_commit_details(repo_path, second)?.parents,
+			vec![first]
+		);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_msg_linefeeds() -> Result<()> {
 		let msg = CommitMessage::from("foo\nbar\r\ntest");
@@ -6,7 +6,7 @@ use super::{
 use crate::{
 	error::Result, sync::repository::repo, StatusItem, StatusItemType,
 };
-use git2::{Diff, Repository};
+use git2::{Diff, Patch, Repository};
 use scopetime::scope_time;
 use std::cmp::Ordering;
 
@@ -26,8 +26,64 @@ pub fn get_commit_files(
 		get_commit_diff(repo_path, &repo, id, None, None)?
 	};
 
-	let res = diff
-		.deltas()
+	Ok(diff_files(&diff))
+}
+
+/// which parent the changes of a (merge) commit are shown against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiffParent {
+	/// index into the parents of the commit
+	Nth(usize),
+	/// only the files that differ from every parent,
+	/// like the combined diff of `git show -c`
+	Combined,
+}
+
+impl Default for DiffParent {
+	fn default() -> Self {
+		Self::Nth(0)
+	}
+}
+
+/// like `get_commit_files` but against the chosen parent
+pub fn get_commit_files_against(
+	repo_path: &RepoPath,
+	id: CommitId,
+	parent: DiffParent,
+) -> Result<Vec<StatusItem>> {
+	scope_time!("get_commit_files_against");
+
+	match parent {
+		DiffParent::Nth(0) => get_commit_files(repo_path, id, None),
+		DiffParent::Nth(idx) => {
+			let repo = repo(repo_path)?;
+			let diff =
+				get_commit_parent_diff(&repo, id, idx, None, None)?;
+
+			Ok(diff_files(&diff))
+		}
+		DiffParent::Combined => {
+			let repo = repo(repo_path)?;
+			let parents = repo.find_commit(id.into())?.parent_count();
+
+			let mut files = get_commit_files(repo_path, id, None)?;
+			for idx in 1..parents {
+				let diff = get_commit_parent_diff(
+					&repo, id, idx, None, None,
+				)?;
+				let other = diff_files(&diff);
+				files.retain(|file| {
+					other.iter().any(|o| o.path == file.path)
+				});
+			}
+
+			Ok(files)
+		}
+	}
+}
+
+fn diff_files(diff: &Diff) -> Vec<StatusItem> {
+	diff.deltas()
 		.map(|delta| {
 			let status = StatusItemType::from(delta.status());
 
@@ -40,9 +96,82 @@ pub fn get_commit_files(
 				status,
 			}
 		})
-		.collect::<Vec<_>>();
+		.collect::<Vec<_>>()
+}
+
+/// number of lines a commit added and removed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CommitLineStats {
+	/// files changed
+	pub files: usize,
+	/// lines added
+	pub added: usize,
+	/// lines removed
+	pub removed: usize,
+}
 
-	Ok(res)
+impl CommitLineStats {
+	/// lines added plus lines removed
+	pub const fn total(&self) -> usize {
+		self.added + self.removed
+	}
+}
+
+/// line stats of a commit compared to the chosen parent.
+///
+/// `DiffParent::Combined` counts the lines of the files
+/// `get_commit_files_against` lists against the first parent.
+/// this computes the full diff of the commit, so it is expensive
+/// to call for a lot of commits.
+pub fn commit_line_stats(
+	repo_path: &RepoPath,
+	id: CommitId,
+	parent: DiffParent,
+) -> Result<CommitLineStats> {
+	scope_time!("commit_line_stats");
+
+	let repo = repo(repo_path)?;
+
+	match parent {
+		DiffParent::Nth(0) => diff_line_stats(&get_commit_diff(
+			repo_path, &repo, id, None, None,
+		)?),
+		DiffParent::Nth(idx) => diff_line_stats(
+			&get_commit_parent_diff(&repo, id, idx, None, None)?,
+		),
+		DiffParent::Combined => {
+			let combined =
+				get_commit_files_against(repo_path, id, parent)?;
+			let diff =
+				get_commit_diff(repo_path, &repo, id, None, None)?;
+
+			let mut stats = CommitLineStats::default();
+			for (idx, file) in diff_files(&diff).iter().enumerate() {
+				if !combined.iter().any(|c| c.path == file.path) {
+					continue;
+				}
+
+				stats.files += 1;
+				if let Some(patch) = Patch::from_diff(&diff, idx)? {
+					let (_, added, removed) = patch.line_stats()?;
+					stats.added += added;
+					stats.removed += removed;
+				}
+			}
+
+			Ok(stats)
+		}
+	}
+}
+
+fn diff_line_stats(diff: &Diff) -> Result<CommitLineStats> {
+	let stats = diff.stats()?;
+
+	Ok(CommitLineStats {
+		files: stats.files_changed(),
+		added: stats.insertions(),
+		removed: stats.deletions(),
+	})
 }
 
 /// get diff of two arbitrary commits
@@ -90,6 +219,38 @@ pub fn get_compare_commits_diff(
 	Ok(diff)
 }
 
+/// get diff of a commit to its parent at index `parent`
+#[allow(clippy::needless_pass_by_value)]
+pub fn get_commit_parent_diff(
+	repo: &Repository,
+	id: CommitId,
+	parent: usize,
+	pathspec: Option<String>,
+	options: Option<DiffOptions>,
+) -> Result<Diff<'_>> {
+	let commit = repo.find_commit(id.into())?;
+	let trees = (commit.parent(parent)?.tree()?, commit.tree()?);
+
+	let mut opts = git2::DiffOptions::new();
+	if let Some(options) = options {
+		opts.context_lines(options.context);
+		opts.ignore_whitespace(options.ignore_whitespace);
+		opts.interhunk_lines(options.interhunk_lines);
+	}
+	if let Some(p) = &pathspec {
+		opts.pathspec(p.clone());
+	}
+	opts.show_binary(true);
+
+	let diff = repo.diff_tree_to_tree(
+		Some(&trees.0),
+		Some(&trees.1),
+		Some(&mut opts),
+	)?;
+
+	Ok(diff)
+}
+
 /// get diff of a commit to its first parent
 pub fn get_commit_diff<'a>(
 	repo_path: &RepoPath,
@@ -147,16 +308,20 @@ pub fn get_commit_diff<'a>(
 
 #[cfg(test)]
 mod tests {
-	use super::get_commit_files;
+	use super::{
+		commit_line_stats, get_commit_files,
+		get_commit_files_against, CommitLineStats, DiffParent,
+	};
 	use crate::{
 		error::Result,
 		sync::{
 			commit, stage_add_file, stash_save,
 			tests::{get_statuses, repo_init},
-			RepoPath,
+			CommitId, RepoPath,
 		},
 		StatusItemType,
 	};
+	use git2::Repository;
 	use std::{fs::File, io::Write, path::Path};
 
 	#[test]
@@ -182,6 +347,47 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_line_stats() -> Result<()> {
+		let file_path = Path::new("file1.txt");
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join(file_path))?
+			.write_all(b"a\nb\nc\n")?;
+		stage_add_file(repo_path, file_path)?;
+		let first = commit(repo_path, "first")?;
+
+		File::create(&root.join(file_path))?.write_all(b"a\nd\n")?;
+		stage_add_file(repo_path, file_path)?;
+		let second = commit(repo_path, "second")?;
+
+		assert_eq!(
+			commit_line_stats(
+				repo_path,
+				first,
+				DiffParent::default()
+			)?,
+			CommitLineStats {
+				files: 1,
+				added: 3,
+				removed: 0
+			}
+		);
+		let stats = commit_line_stats(
+			repo_path,
+			second,
+			DiffParent::default(),
+		)?;
+		assert_eq!(stats.added, 1);
+		assert_eq!(stats.removed, 2);
+		assert_eq!(stats.total(), 3);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_stashed_untracked() -> Result<()> {
 		let file_path = Path::new("file1.txt");
@@ -232,4 +438,91 @@ mod tests {
 
 		Ok(())
 	}
+
+	fn commit_tree(
+		repo: &Repository,
+		files: &[(&str, &str)],
+		parents: &[CommitId],
+	) -> CommitId {
+		let mut builder = repo.treebuilder(None).unwrap();
+		for (name, content) in files {
+			let blob = repo.blob(content.as_bytes()).unwrap();
+			builder.insert(name, blob, 0o100_644).unwrap();
+		}
+		let tree = repo.find_tree(builder.write().unwrap()).unwrap();
+
+		let parents = parents
+			.iter()
+			.map(|id| repo.find_commit((*id).into()).unwrap())
+			.collect::<Vec<_>>();
+		let parents = parents.iter().collect::<Vec<_>>();
+
+		let sig = repo.signature().unwrap();
+		repo.commit(None, &sig, &sig, "msg", &tree, &parents)
+			.unwrap()
+			.into()
+	}
+
+	#[test]
+	fn test_merge_files_against_parent() -> Result<()> {
+		let (_td, repo) = repo_init()?;
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let base = commit_tree(
+			&repo,
+			&[("a", "1"), ("b", "1"), ("c", "1")],
+			&[],
+		);
+		let ours = commit_tree(
+			&repo,
+			&[("a", "2"), ("b", "1"), ("c", "1")],
+			&[base],
+		);
+		let theirs = commit_tree(
+			&repo,
+			&[("a", "1"), ("b", "2"), ("c", "1")],
+			&[base],
+		);
+		let merge = commit_tree(
+			&repo,
+			&[("a", "2"), ("b", "2"), ("c", "3")],
+			&[ours, theirs],
+		);
+
+		let paths = |parent| -> Result<Vec<String>> {
+			Ok(get_commit_files_against(repo_path, merge, parent)?
+				.into_iter()
+				.map(|f| f.path)
+				.collect())
+		};
+
+		assert_eq!(paths(DiffParent::Nth(0))?, vec!["b", "c"]);
+		assert_eq!(paths(DiffParent::Nth(1))?, vec!["a", "c"]);
+		assert_eq!(paths(DiffParent::Combined)?, vec!["c"]);
+		assert!(paths(DiffParent::Nth(2)).is_err());
+
+		let stats = |parent| -> Result<(usize, usize, usize)> {
+			let stats = commit_line_stats(repo_path, merge, parent)?;
+			Ok((stats.files, stats.added, stats.removed))
+		};
+
+		assert_eq!(stats(DiffParent::Nth(0))?, (2, 2, 2));
+		assert_eq!(stats(DiffParent::Nth(1))?, (2, 2, 2));
+		assert_eq!(stats(DiffParent::Combined)?, (1, 1, 1));
+
+		// a single parent has nothing to combine
+		assert_eq!(
+			get_commit_files_against(
+				repo_path,
+				ours,
+				DiffParent::Combined
+			)?
+			.len(),
+			1
+		);
+
+		Ok(())
+	}
 }
@@ -1,6 +1,6 @@
 use super::RepoPath;
 use crate::{error::Result, sync::repository::repo};
-use git2::{Commit, Error, Oid};
+use git2::{Commit, Error, ErrorCode, Oid};
 use scopetime::scope_time;
 use unicode_truncate::UnicodeTruncateStr;
 
@@ -25,6 +25,11 @@ impl CommitId {
 	pub fn get_short_string(&self) -> String {
 		self.to_string().chars().take(7).collect()
 	}
+
+	/// parse a full hex hash, does not check whether the commit exists
+	pub fn from_str_unchecked(commit_id_str: &str) -> Result<Self> {
+		Ok(Self(Oid::from_str(commit_id_str)?))
+	}
 }
 
 //TODO: remove once clippy fixed: https://github.com/rust-lang/rust-clippy/issues/6983
@@ -52,12 +57,17 @@ impl From<Oid> for CommitId {
 pub struct CommitInfo {
 	///
 	pub message: String,
-	///
+	/// committer date
 	pub time: i64,
+	/// author date, differs from `time` if the commit was
+	/// rebased, amended or applied later
+	pub author_time: i64,
 	///
 	pub author: String,
 	///
 	pub id: CommitId,
+	/// ids of the parent commits (empty for a root commit)
+	pub parents: Vec<CommitId>,
 }
 
 ///
@@ -87,7 +97,9 @@ pub fn get_commits_info(
 				message,
 				author,
 				time: c.time().seconds(),
+				author_time: c.author().when().seconds(),
 				id: CommitId(c.id()),
+				parents: c.parent_ids().map(CommitId).collect(),
 			}
 		})
 		.collect::<Vec<_>>();
@@ -111,10 +123,62 @@ pub fn get_commit_info(
 		message: commit.message().unwrap_or("").into(),
 		author: author.name().unwrap_or("<unknown>").into(),
 		time: commit.time().seconds(),
+		author_time: author.when().seconds(),
 		id: CommitId(commit.id()),
+		parents: commit.parent_ids().map(CommitId).collect(),
 	})
 }
 
+/// the commits of `ids` authored by `author` (matched by name),
+/// in the order of `ids`
+pub fn commits_by_author(
+	repo_path: &RepoPath,
+	ids: &[CommitId],
+	author: &str,
+) -> Result<Vec<CommitId>> {
+	scope_time!("commits_by_author");
+
+	let repo = repo(repo_path)?;
+
+	let mut res = Vec::new();
+	for id in ids {
+		let commit = repo.find_commit((*id).into())?;
+		if commit.author().name() == Some(author) {
+			res.push(*id);
+		}
+	}
+
+	Ok(res)
+}
+
+/// hex id of the tree `commit_id` points to
+pub fn commit_tree_id(
+	repo_path: &RepoPath,
+	commit_id: CommitId,
+) -> Result<String> {
+	scope_time!("commit_tree_id");
+
+	let repo = repo(repo_path)?;
+	let commit = repo.find_commit(commit_id.into())?;
+
+	Ok(commit.tree_id().to_string())
+}
+
+/// whether `commit_id` carries a (gpg) signature,
+/// this does not verify the signature
+pub fn commit_signed(
+	repo_path: &RepoPath,
+	commit_id: CommitId,
+) -> Result<bool> {
+	let repo = repo(repo_path)?;
+
+	match repo.extract_signature(&commit_id.into(), None) {
+		Ok(_) => Ok(true),
+		Err(e) if e.code() == ErrorCode::NotFound => Ok(false),
+		Err(e) => Err(e.into()),
+	}
+}
+
 /// if `message_limit` is set the message will be
 /// limited to the first line and truncated to fit
 pub fn get_message(
@@ -135,7 +199,10 @@ pub fn get_message(
 
 #[cfg(test)]
 mod tests {
-	use super::get_commits_info;
+	use super::{
+		commit_signed, commit_tree_id, commits_by_author,
+		get_commits_info, CommitId,
+	};
 	use crate::{
 		error::Result,
 		sync::{
@@ -166,11 +233,109 @@ mod tests {
 		assert_eq!(res.len(), 2);
 		assert_eq!(res[0].message.as_str(), "commit2");
 		assert_eq!(res[0].author.as_str(), "name");
+		assert_eq!(res[0].parents, vec![c1]);
+		assert_eq!(res[0].author_time, res[0].time);
 		assert_eq!(res[1].message.as_str(), "commit1");
+		assert_eq!(res[1].parents.len(), 0);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_commits_by_author() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let c1 = commit(repo_path, "commit1").unwrap();
+
+		repo.config()?.set_str("user.name", "other")?;
+		File::create(root.join(file_path))?.write_all(b"b")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let c2 = commit(repo_path, "commit2").unwrap();
+
+		assert_eq!(
+			commits_by_author(repo_path, &[c2, c1], "name")?,
+			vec![c1]
+		);
+		assert_eq!(
+			commits_by_author(repo_path, &[c2, c1], "other")?,
+			vec![c2]
+		);
+		assert!(commits_by_author(repo_path, &[c2, c1], "nobody")?
+			.is_empty());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_commit_tree_id() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let c1 = commit(repo_path, "commit1").unwrap();
+
+		let tree = repo.find_commit(c1.into())?.tree_id();
+
+		assert_eq!(commit_tree_id(repo_path, c1)?, tree.to_string());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_commit_signed() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let c1 = commit(repo_path, "commit1").unwrap();
+
+		assert!(!commit_signed(repo_path, c1)?);
+
+		let c1 = repo.find_commit(c1.into())?;
+		let sig = repo.signature()?;
+		let content = repo.commit_create_buffer(
+			&sig,
+			&sig,
+			"signed",
+			&c1.tree()?,
+			&[&c1],
+		)?;
+		let signed = repo.commit_signed(
+			content.as_str().unwrap(),
+			"-----BEGIN PGP SIGNATURE-----\n-----END PGP SIGNATURE-----",
+			None,
+		)?;
+
+		assert!(commit_signed(repo_path, signed.into())?);
 
 		Ok(())
 	}
 
+	#[test]
+	fn test_commit_id_from_str() {
+		let id = CommitId::from_str_unchecked(
+			"0123456789abcdef0123456789abcdef01234567",
+		)
+		.unwrap();
+
+		assert_eq!(id.get_short_string(), "0123456");
+		assert!(CommitId::from_str_unchecked("no hash").is_err());
+	}
+
 	#[test]
 	fn test_log_first_msg_line() -> Result<()> {
 		let file_path = Path::new("foo");
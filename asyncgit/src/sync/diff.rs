@@ -1,7 +1,10 @@
 //! sync git api for fetching a diff
 
 use super::{
-	commit_files::{get_commit_diff, get_compare_commits_diff},
+	commit_files::{
+		get_commit_diff, get_commit_parent_diff,
+		get_compare_commits_diff,
+	},
 	utils::{get_head_repo, work_dir},
 	CommitId, RepoPath,
 };
@@ -226,6 +229,25 @@ pub fn get_diff_commit(
 	raw_diff_to_file_diff(&diff, work_dir)
 }
 
+/// returns diff of a specific file inside a commit compared to
+/// its parent at index `parent`, see `get_commit_parent_diff`
+pub fn get_diff_commit_parent(
+	repo_path: &RepoPath,
+	id: CommitId,
+	parent: usize,
+	p: String,
+	options: Option<DiffOptions>,
+) -> Result<FileDiff> {
+	scope_time!("get_diff_commit_parent");
+
+	let repo = repo(repo_path)?;
+	let work_dir = work_dir(&repo)?;
+	let diff =
+		get_commit_parent_diff(&repo, id, parent, Some(p), options)?;
+
+	raw_diff_to_file_diff(&diff, work_dir)
+}
+
 /// get file changes of a diff between two commits
 pub fn get_diff_commits(
 	repo_path: &RepoPath,
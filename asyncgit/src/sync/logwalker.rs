@@ -1,11 +1,18 @@
 use super::CommitId;
 use crate::sync::RepoPath;
-use crate::{error::Result, sync::commit_files::get_commit_diff};
-use git2::{Commit, Oid, Repository};
+use crate::{
+	error::Result,
+	sync::{
+		commit_files::get_commit_diff,
+		merge::merge_commit_has_conflict_markers,
+	},
+};
+use git2::{Commit, Delta, DiffFindOptions, Oid, Repository};
 use std::{
 	cmp::Ordering,
-	collections::{BinaryHeap, HashSet},
-	sync::Arc,
+	collections::{BinaryHeap, HashMap, HashSet},
+	path::Path,
+	sync::{Arc, Mutex},
 };
 
 struct TimeOrderedCommit<'a>(Commit<'a>);
@@ -59,6 +66,158 @@ pub fn diff_contains_file(
 	))
 }
 
+/// path a followed file had in each commit that touched it,
+/// see `diff_contains_file_following_renames`
+pub type FollowedPaths = Arc<Mutex<HashMap<CommitId, String>>>;
+
+/// like `diff_contains_file` but follows the file across renames.
+///
+/// once a commit turns out to have renamed the file the older commits
+/// are matched against its old path. the path of the file in each
+/// matched commit is recorded in `paths`. renames are only looked for
+/// in commits adding the file, so the expensive full diff is only
+/// computed for those.
+///
+/// the followed path depends on the commits walked so far: the filter
+/// starts over (clearing `paths`) whenever it sees the first commit
+/// of its first walk again, so it can be reused for walks from the
+/// same start but not for walks from another one.
+pub fn diff_contains_file_following_renames(
+	repo_path: RepoPath,
+	file_path: String,
+	paths: FollowedPaths,
+) -> LogWalkerFilter {
+	let first_commit = Mutex::new(None);
+	let current_path = Mutex::new(file_path.clone());
+
+	Arc::new(Box::new(
+		move |repo: &Repository,
+		      commit_id: &CommitId|
+		      -> Result<bool> {
+			let mut current_path = current_path.lock()?;
+
+			let restarted = {
+				let mut first_commit = first_commit.lock()?;
+				let first_call = first_commit.is_none();
+				*first_commit.get_or_insert(*commit_id) == *commit_id
+					&& !first_call
+			};
+			if restarted {
+				current_path.clone_from(&file_path);
+				paths.lock()?.clear();
+			}
+
+			let diff = get_commit_diff(
+				&repo_path,
+				repo,
+				*commit_id,
+				Some(current_path.clone()),
+				None,
+			)?;
+
+			if diff.deltas().len() == 0 {
+				return Ok(false);
+			}
+
+			paths.lock()?.insert(*commit_id, current_path.clone());
+
+			if diff
+				.deltas()
+				.any(|delta| delta.status() == Delta::Added)
+			{
+				let mut diff = get_commit_diff(
+					&repo_path, repo, *commit_id, None, None,
+				)?;
+				diff.find_similar(Some(
+					DiffFindOptions::new().renames(true),
+				))?;
+
+				let old_path = diff
+					.deltas()
+					.find(|delta| {
+						delta.status() == Delta::Renamed
+							&& delta.new_file().path()
+								== Some(Path::new(
+									current_path.as_str(),
+								))
+					})
+					.and_then(|delta| {
+						delta.old_file().path().and_then(Path::to_str)
+					})
+					.map(String::from);
+
+				if let Some(old_path) = old_path {
+					*current_path = old_path;
+				}
+			}
+
+			Ok(true)
+		},
+	))
+}
+
+/// commits whose commit time (seconds since the epoch)
+/// satisfies `matches`, e.g. commits made on a weekend
+pub fn commit_time_matches(
+	matches: impl Fn(i64) -> bool + Send + Sync + 'static,
+) -> LogWalkerFilter {
+	Arc::new(Box::new(
+		move |repo: &Repository,
+		      commit_id: &CommitId|
+		      -> Result<bool> {
+			let commit = repo.find_commit((*commit_id).into())?;
+
+			Ok(matches(commit.time().seconds()))
+		},
+	))
+}
+
+/// skips commits that do not change anything
+/// compared to their first parent
+pub fn non_empty_commits(repo_path: RepoPath) -> LogWalkerFilter {
+	Arc::new(Box::new(
+		move |repo: &Repository,
+		      commit_id: &CommitId|
+		      -> Result<bool> {
+			let diff = get_commit_diff(
+				&repo_path, repo, *commit_id, None, None,
+			)?;
+
+			Ok(diff.deltas().len() > 0)
+		},
+	))
+}
+
+/// only merges still containing conflict markers,
+/// see `merge_has_unresolved_conflicts`
+///
+/// being a heuristic it has false negatives: octopus merges, markers
+/// of a non default `conflict-marker-size` and conflicts that
+/// re-merging the parents does not reproduce (e.g. merges made with
+/// another strategy) are never matched. re-merging is expensive, so
+/// results are cached per filter for walking the log again.
+pub fn unresolved_conflict_merges() -> LogWalkerFilter {
+	let cache = Mutex::new(HashMap::<CommitId, bool>::new());
+
+	Arc::new(Box::new(
+		move |repo: &Repository,
+		      commit_id: &CommitId|
+		      -> Result<bool> {
+			let cached = cache.lock()?.get(commit_id).copied();
+
+			if let Some(conflicted) = cached {
+				return Ok(conflicted);
+			}
+
+			let conflicted =
+				merge_commit_has_conflict_markers(repo, *commit_id)?;
+			cache.lock()?.insert(*commit_id, conflicted);
+
+			Ok(conflicted)
+		},
+	))
+}
+
 ///
 pub struct LogWalker<'a> {
 	commits: BinaryHeap<TimeOrderedCommit<'a>>,
@@ -66,12 +225,31 @@ pub struct LogWalker<'a> {
 	limit: usize,
 	repo: &'a Repository,
 	filter: Option<LogWalkerFilter>,
+	first_parent: bool,
+	since: Option<i64>,
+	topo_order: bool,
+	no_merges: bool,
+	/// children not yet read per commit, see `topo_order`
+	pending_children: Option<HashMap<Oid, usize>>,
+	/// commits whose children were all read, next one on top
+	topo_ready: Vec<Commit<'a>>,
 }
 
 impl<'a> LogWalker<'a> {
 	///
 	pub fn new(repo: &'a Repository, limit: usize) -> Result<Self> {
-		let c = repo.head()?.peel_to_commit()?;
+		let head = repo.head()?.peel_to_commit()?;
+
+		Self::new_at(repo, head.id().into(), limit)
+	}
+
+	/// walk starting at `start` instead of `HEAD`
+	pub fn new_at(
+		repo: &'a Repository,
+		start: CommitId,
+		limit: usize,
+	) -> Result<Self> {
+		let c = repo.find_commit(start.into())?;
 
 		let mut commits = BinaryHeap::with_capacity(10);
 		commits.push(TimeOrderedCommit(c));
@@ -82,6 +260,12 @@ impl<'a> LogWalker<'a> {
 			visited: HashSet::with_capacity(1000),
 			repo,
 			filter: None,
+			first_parent: false,
+			since: None,
+			topo_order: false,
+			no_merges: false,
+			pending_children: None,
+			topo_ready: Vec::new(),
 		})
 	}
 
@@ -91,25 +275,129 @@ impl<'a> LogWalker<'a> {
 		Self { filter, ..self }
 	}
 
+	/// only follow the first parent of merge commits
+	/// (like `git log --first-parent`)
+	#[must_use]
+	pub fn first_parent(self, first_parent: bool) -> Self {
+		Self {
+			first_parent,
+			..self
+		}
+	}
+
+	/// stop the walk at the first commit older than `since`
+	/// (seconds since epoch, like `git log --since`)
+	#[must_use]
+	pub fn since(self, since: Option<i64>) -> Self {
+		Self { since, ..self }
+	}
+
+	/// never read a commit before all of its children
+	/// (like `git log --topo-order`), regardless of commit dates.
+	/// the first `read` has to walk all commits to count their children
+	#[must_use]
+	pub fn topo_order(self, topo_order: bool) -> Self {
+		Self { topo_order, ..self }
+	}
+
+	/// skip merge commits (like `git log --no-merges`),
+	/// their parents are still walked
+	#[must_use]
+	pub fn no_merges(self, no_merges: bool) -> Self {
+		Self { no_merges, ..self }
+	}
+
+	/// whether `c` passes `no_merges` and the filter
+	fn should_include(&self, c: &Commit) -> Result<bool> {
+		if self.no_merges && c.parent_count() > 1 {
+			return Ok(false);
+		}
+
+		self.filter.as_ref().map_or(Ok(true), |filter| {
+			filter(self.repo, &c.id().into())
+		})
+	}
+
 	///
 	pub fn read(&mut self, out: &mut Vec<CommitId>) -> Result<usize> {
+		if self.topo_order {
+			return self.read_topo(out);
+		}
+
 		let mut count = 0_usize;
 
 		while let Some(c) = self.commits.pop() {
-			for p in c.0.parents() {
+			if let Some(since) = self.since {
+				// commits come out newest first: all the rest are older
+				if c.0.time().seconds() < since {
+					self.commits.clear();
+					break;
+				}
+			}
+
+			let parents_to_visit =
+				if self.first_parent { 1 } else { usize::MAX };
+
+			for p in c.0.parents().take(parents_to_visit) {
 				self.visit(p);
 			}
 
-			let id: CommitId = c.0.id().into();
-			let commit_should_be_included =
-				if let Some(ref filter) = self.filter {
-					filter(self.repo, &id)?
-				} else {
-					true
-				};
+			if self.should_include(&c.0)? {
+				out.push(c.0.id().into());
+			}
 
-			if commit_should_be_included {
-				out.push(id);
+			count += 1;
+			if count == self.limit {
+				break;
+			}
+		}
+
+		Ok(count)
+	}
+
+	fn read_topo(
+		&mut self,
+		out: &mut Vec<CommitId>,
+	) -> Result<usize> {
+		if self.pending_children.is_none() {
+			self.pending_children = Some(self.count_children());
+			let since = self.since;
+			self.topo_ready.extend(
+				self.commits
+					.drain()
+					.map(|c| c.0)
+					.filter(|c| !Self::too_old(since, c)),
+			);
+		}
+
+		let parents_to_visit =
+			if self.first_parent { 1 } else { usize::MAX };
+
+		let mut count = 0_usize;
+
+		while let Some(c) = self.topo_ready.pop() {
+			let parents = c
+				.parents()
+				.take(parents_to_visit)
+				.collect::<Vec<_>>();
+
+			// reversed so that the first parent is read next
+			for p in parents.into_iter().rev() {
+				let children = self
+					.pending_children
+					.as_mut()
+					.and_then(|pending| pending.get_mut(&p.id()));
+
+				if let Some(children) = children {
+					*children = children.saturating_sub(1);
+					if *children == 0 {
+						self.topo_ready.push(p);
+					}
+				}
+			}
+
+			if self.should_include(&c)? {
+				out.push(c.id().into());
 			}
 
 			count += 1;
@@ -121,6 +409,41 @@ impl<'a> LogWalker<'a> {
 		Ok(count)
 	}
 
+	/// number of children of every commit reachable from the start
+	/// (the same commits the date ordered walk would visit)
+	fn count_children(&self) -> HashMap<Oid, usize> {
+		let parents_to_visit =
+			if self.first_parent { 1 } else { usize::MAX };
+
+		let mut children = HashMap::with_capacity(1000);
+		let mut todo = self
+			.commits
+			.iter()
+			.map(|c| c.0.clone())
+			.filter(|c| !Self::too_old(self.since, c))
+			.collect::<Vec<_>>();
+
+		while let Some(c) = todo.pop() {
+			for p in c.parents().take(parents_to_visit) {
+				if Self::too_old(self.since, &p) {
+					continue;
+				}
+
+				let count = children.entry(p.id()).or_insert(0);
+				*count += 1;
+				if *count == 1 {
+					todo.push(p);
+				}
+			}
+		}
+
+		children
+	}
+
+	fn too_old(since: Option<i64>, c: &Commit) -> bool {
+		since.map_or(false, |since| c.time().seconds() < since)
+	}
+
 	//
 	fn visit(&mut self, c: Commit<'a>) {
 		if !self.visited.contains(&c.id()) {
@@ -136,8 +459,11 @@ mod tests {
 	use crate::error::Result;
 	use crate::sync::RepoPath;
 	use crate::sync::{
-		commit, get_commits_info, stage_add_file,
-		tests::repo_init_empty,
+		commit, get_commits_info, stage_add_file, stage_addremoved,
+		tests::{
+			debug_cmd_print, repo_init_empty, write_commit_file_at,
+		},
+		utils::get_head_repo,
 	};
 	use pretty_assertions::assert_eq;
 	use std::{fs::File, io::Write, path::Path};
@@ -200,6 +526,30 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_logwalker_new_at() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let oid1 = commit(repo_path, "commit1").unwrap();
+		File::create(&root.join(file_path))?.write_all(b"b")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		commit(repo_path, "commit2").unwrap();
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new_at(&repo, oid1, 100)?;
+		walk.read(&mut items).unwrap();
+
+		assert_eq!(items, vec![oid1]);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_logwalker_with_filter() -> Result<()> {
 		let file_path = Path::new("foo");
@@ -254,4 +604,282 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_logwalker_following_renames() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: RepoPath =
+			root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join("foo"))?.write_all(
+			b"some content\nthat is kept\nby the rename\n",
+		)?;
+		stage_add_file(&repo_path, Path::new("foo")).unwrap();
+		let first = commit(&repo_path, "add foo").unwrap();
+
+		std::fs::rename(root.join("foo"), root.join("bar"))?;
+		stage_addremoved(&repo_path, Path::new("foo")).unwrap();
+		stage_add_file(&repo_path, Path::new("bar")).unwrap();
+		let renamed = commit(&repo_path, "rename foo").unwrap();
+
+		File::create(&root.join("other"))?.write_all(b"a")?;
+		stage_add_file(&repo_path, Path::new("other")).unwrap();
+		let _other = commit(&repo_path, "other").unwrap();
+
+		let paths = FollowedPaths::default();
+		let filter = diff_contains_file_following_renames(
+			repo_path,
+			"bar".into(),
+			paths.clone(),
+		);
+
+		// a second walk with the same filter starts over at `bar`
+		for _ in 0..2 {
+			let mut items = Vec::new();
+			let mut walker = LogWalker::new(&repo, 100)?
+				.filter(Some(filter.clone()));
+			walker.read(&mut items).unwrap();
+
+			assert_eq!(items, vec![renamed, first]);
+
+			let paths = paths.lock()?;
+			assert_eq!(paths.len(), 2);
+			assert_eq!(paths.get(&renamed).unwrap(), "bar");
+			assert_eq!(paths.get(&first).unwrap(), "foo");
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_logwalker_non_empty_commits() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: RepoPath =
+			root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(&repo_path, file_path).unwrap();
+		let changed = commit(&repo_path, "changed").unwrap();
+		let _empty = commit(&repo_path, "empty").unwrap();
+
+		let mut items = Vec::new();
+		let mut walker = LogWalker::new(&repo, 100)?
+			.filter(Some(non_empty_commits(repo_path)));
+		walker.read(&mut items).unwrap();
+
+		assert_eq!(items, vec![changed]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_logwalker_first_parent() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(&root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let c1 = commit(repo_path, "commit1").unwrap();
+
+		debug_cmd_print(repo_path, "git checkout -b feature");
+		File::create(&root.join(file_path))?.write_all(b"b")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let feature = commit(repo_path, "feature").unwrap();
+
+		debug_cmd_print(repo_path, "git checkout master");
+		debug_cmd_print(repo_path, "git merge --no-ff feature");
+		let merge = get_head_repo(&repo).unwrap();
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new(&repo, 100)?;
+		walk.read(&mut items).unwrap();
+
+		assert_eq!(items.len(), 3);
+		assert!(items.contains(&feature));
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new(&repo, 100)?.first_parent(true);
+		walk.read(&mut items).unwrap();
+
+		assert_eq!(items, vec![merge, c1]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_logwalker_no_merges() -> Result<()> {
+		let file_path = Path::new("foo");
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		File::create(root.join(file_path))?.write_all(b"a")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let c1 = commit(repo_path, "commit1").unwrap();
+
+		debug_cmd_print(repo_path, "git checkout -b feature");
+		File::create(root.join(file_path))?.write_all(b"b")?;
+		stage_add_file(repo_path, file_path).unwrap();
+		let feature = commit(repo_path, "feature").unwrap();
+
+		debug_cmd_print(repo_path, "git checkout master");
+		debug_cmd_print(repo_path, "git merge --no-ff feature");
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new(&repo, 100)?.no_merges(true);
+		walk.read(&mut items).unwrap();
+
+		// same commit times: the date order between both is arbitrary
+		assert_eq!(items.len(), 2);
+		assert!(items.contains(&feature) && items.contains(&c1));
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new(&repo, 100)?
+			.no_merges(true)
+			.topo_order(true);
+		walk.read(&mut items).unwrap();
+
+		assert_eq!(items, vec![feature, c1]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_logwalker_since() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+
+		let _c1 = write_commit_file_at(
+			&repo,
+			"foo",
+			"a",
+			"commit1",
+			git2::Time::new(100, 0),
+		);
+		let c2 = write_commit_file_at(
+			&repo,
+			"foo",
+			"b",
+			"commit2",
+			git2::Time::new(200, 0),
+		);
+		let c3 = write_commit_file_at(
+			&repo,
+			"foo",
+			"c",
+			"commit3",
+			git2::Time::new(300, 0),
+		);
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new(&repo, 100)?.since(Some(200));
+		walk.read(&mut items).unwrap();
+
+		assert_eq!(items, vec![c3, c2]);
+
+		let mut items = Vec::new();
+		walk.read(&mut items).unwrap();
+
+		assert!(items.is_empty());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_logwalker_commit_time_matches() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+
+		let c1 = write_commit_file_at(
+			&repo,
+			"foo",
+			"a",
+			"commit1",
+			git2::Time::new(100, 0),
+		);
+		let _c2 = write_commit_file_at(
+			&repo,
+			"foo",
+			"b",
+			"commit2",
+			git2::Time::new(200, 60),
+		);
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new(&repo, 100)?
+			.filter(Some(commit_time_matches(|time| time < 150)));
+		walk.read(&mut items).unwrap();
+
+		assert_eq!(items, vec![c1]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_logwalker_topo_order() -> Result<()> {
+		let (_td, repo) = repo_init_empty().unwrap();
+
+		let tree =
+			repo.find_tree(repo.treebuilder(None)?.write()?)?;
+		let commit_at = |msg: &str, secs: i64, parents: &[Oid]| {
+			let sig = git2::Signature::new(
+				"name",
+				"email",
+				&git2::Time::new(secs, 0),
+			)
+			.unwrap();
+			let parents = parents
+				.iter()
+				.map(|id| repo.find_commit(*id).unwrap())
+				.collect::<Vec<_>>();
+			let parents = parents.iter().collect::<Vec<_>>();
+
+			repo.commit(None, &sig, &sig, msg, &tree, &parents)
+				.unwrap()
+		};
+
+		// `old` claims to be older than its parent `base`
+		let base = commit_at("base", 100, &[]);
+		let old = commit_at("old", 50, &[base]);
+		let new = commit_at("new", 500, &[base]);
+		let merge = commit_at("merge", 600, &[new, old]);
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new_at(&repo, merge.into(), 100)?;
+		walk.read(&mut items).unwrap();
+
+		assert_eq!(
+			items,
+			vec![merge.into(), new.into(), base.into(), old.into()]
+		);
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new_at(&repo, merge.into(), 100)?
+			.topo_order(true);
+		walk.read(&mut items).unwrap();
+
+		assert_eq!(
+			items,
+			vec![merge.into(), new.into(), old.into(), base.into()]
+		);
+
+		let mut items = Vec::new();
+		let mut walk = LogWalker::new_at(&repo, merge.into(), 2)?
+			.topo_order(true)
+			.since(Some(80));
+		walk.read(&mut items).unwrap();
+		walk.read(&mut items).unwrap();
+
+		assert_eq!(
+			items,
+			vec![merge.into(), new.into(), base.into()]
+		);
+
+		Ok(())
+	}
 }
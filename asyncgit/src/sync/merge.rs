@@ -9,8 +9,9 @@ use crate::{
 		reset_stage, reset_workdir, CommitId,
 	},
 };
-use git2::{BranchType, Commit, MergeOptions, Repository};
+use git2::{BranchType, Commit, ErrorCode, MergeOptions, Repository};
 use scopetime::scope_time;
+use std::path::Path;
 
 use super::{
 	rebase::{RebaseProgress, RebaseState},
@@ -151,6 +152,85 @@ pub fn merge_commit(
 	Ok(id)
 }
 
+/// best common ancestor of `a` and `b` (like `git merge-base`),
+/// `None` if they have none
+pub fn merge_base(
+	repo_path: &RepoPath,
+	a: CommitId,
+	b: CommitId,
+) -> Result<Option<CommitId>> {
+	scope_time!("merge_base");
+
+	let repo = repo(repo_path)?;
+	match repo.merge_base(a.into(), b.into()) {
+		Ok(id) => Ok(Some(id.into())),
+		Err(e) if e.code() == ErrorCode::NotFound => Ok(None),
+		Err(e) => Err(e.into()),
+	}
+}
+
+/// heuristic: true if `id` merges conflicting parents leaving markers
+///
+/// one of the conflicting files has to still contain conflict markers,
+/// conflicts that were resolved are not matched, neither are markers
+/// of a non default `conflict-marker-size` or octopus merges.
+pub fn merge_has_unresolved_conflicts(
+	repo_path: &RepoPath,
+	id: CommitId,
+) -> Result<bool> {
+	scope_time!("merge_has_unresolved_conflicts");
+
+	let repo = repo(repo_path)?;
+	merge_commit_has_conflict_markers(&repo, id)
+}
+
+pub fn merge_commit_has_conflict_markers(
+	repo: &Repository,
+	id: CommitId,
+) -> Result<bool> {
+	let commit = repo.find_commit(id.into())?;
+	if commit.parent_count() != 2 {
+		return Ok(false);
+	}
+
+	let index = repo.merge_commits(
+		&commit.parent(0)?,
+		&commit.parent(1)?,
+		None,
+	)?;
+	if !index.has_conflicts() {
+		return Ok(false);
+	}
+
+	let tree = commit.tree()?;
+	for conflict in index.conflicts()? {
+		let conflict = conflict?;
+		let path = conflict.our.or(conflict.their).map(|entry| {
+			String::from_utf8_lossy(&entry.path).to_string()
+		});
+
+		let blob = path
+			.and_then(|path| tree.get_path(Path::new(&path)).ok())
+			.and_then(|entry| repo.find_blob(entry.id()).ok());
+
+		if blob.map_or(false, |blob| {
+			has_conflict_markers(blob.content())
+		}) {
+			return Ok(true);
+		}
+	}
+
+	Ok(false)
+}
+
+fn has_conflict_markers(content: &[u8]) -> bool {
+	let mut lines = content.split(|c| *c == b'\n');
+
+	lines.any(|line| line.starts_with(b"<<<<<<< "))
+		&& lines.any(|line| line.starts_with(b"======="))
+		&& lines.any(|line| line.starts_with(b">>>>>>> "))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -185,4 +265,82 @@ mod tests {
 
 		assert_eq!(mergeheads[0], c1);
 	}
+
+	fn commit_content(
+		repo: &Repository,
+		content: &str,
+		parents: &[CommitId],
+	) -> CommitId {
+		let blob = repo.blob(content.as_bytes()).unwrap();
+		let mut builder = repo.treebuilder(None).unwrap();
+		builder.insert("test.txt", blob, 0o100_644).unwrap();
+		let tree = repo.find_tree(builder.write().unwrap()).unwrap();
+
+		let parents = parents
+			.iter()
+			.map(|id| repo.find_commit((*id).into()).unwrap())
+			.collect::<Vec<_>>();
+		let parents = parents.iter().collect::<Vec<_>>();
+
+		let sig = repo.signature().unwrap();
+		repo.commit(None, &sig, &sig, content, &tree, &parents)
+			.unwrap()
+			.into()
+	}
+
+	#[test]
+	fn test_unresolved_conflicts() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let base = commit_content(&repo, "base\n", &[]);
+		let ours = commit_content(&repo, "ours\n", &[base]);
+		let theirs = commit_content(&repo, "theirs\n", &[base]);
+
+		let unresolved = commit_content(
+			&repo,
+			"<<<<<<< ours\nours\n=======\ntheirs\n>>>>>>> theirs\n",
+			&[ours, theirs],
+		);
+		let resolved =
+			commit_content(&repo, "ours\ntheirs\n", &[ours, theirs]);
+
+		assert!(merge_has_unresolved_conflicts(
+			repo_path, unresolved
+		)
+		.unwrap());
+		assert!(!merge_has_unresolved_conflicts(repo_path, resolved)
+			.unwrap());
+		assert!(
+			!merge_has_unresolved_conflicts(repo_path, ours).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_merge_base() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let base = commit_content(&repo, "base\n", &[]);
+		let ours = commit_content(&repo, "ours\n", &[base]);
+		let theirs = commit_content(&repo, "theirs\n", &[base]);
+		let unrelated = commit_content(&repo, "unrelated\n", &[]);
+
+		assert_eq!(
+			merge_base(repo_path, ours, theirs).unwrap(),
+			Some(base)
+		);
+		assert_eq!(
+			merge_base(repo_path, ours, base).unwrap(),
+			Some(base)
+		);
+		assert_eq!(
+			merge_base(repo_path, ours, unrelated).unwrap(),
+			None
+		);
+	}
 }
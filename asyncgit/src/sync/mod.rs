@@ -23,6 +23,7 @@ mod rebase;
 pub mod remotes;
 mod repository;
 mod reset;
+mod squash;
 mod staging;
 mod stash;
 mod state;
@@ -30,8 +31,9 @@ pub mod status;
 mod tags;
 mod tree;
 pub mod utils;
+mod worktree;
 
-pub use blame::{blame_file, BlameHunk, FileBlame};
+pub use blame::{blame_check, blame_file, BlameHunk, FileBlame};
 pub use branch::{
 	branch_compare_upstream, checkout_branch, config_is_pull_rebase,
 	create_branch, delete_branch, get_branch_remote,
@@ -44,9 +46,13 @@ pub use commit::{amend, commit, tag_commit};
 pub use commit_details::{
 	get_commit_details, CommitDetails, CommitMessage, CommitSignature,
 };
-pub use commit_files::get_commit_files;
+pub use commit_files::{
+	commit_line_stats, get_commit_files, get_commit_files_against,
+	CommitLineStats, DiffParent,
+};
 pub use commit_revert::{commit_revert, revert_commit, revert_head};
 pub use commits_info::{
+	commit_signed, commit_tree_id, commits_by_author,
 	get_commit_info, get_commits_info, CommitId, CommitInfo,
 };
 pub use config::{
@@ -60,20 +66,27 @@ pub use hooks::{
 };
 pub use hunks::{reset_hunk, stage_hunk, unstage_hunk};
 pub use ignore::add_to_ignore;
-pub use logwalker::{diff_contains_file, LogWalker, LogWalkerFilter};
+pub use logwalker::{
+	commit_time_matches, diff_contains_file,
+	diff_contains_file_following_renames, non_empty_commits,
+	unresolved_conflict_merges, FollowedPaths, LogWalker,
+	LogWalkerFilter,
+};
 pub use merge::{
 	abort_pending_rebase, abort_pending_state,
-	continue_pending_rebase, merge_branch, merge_commit, merge_msg,
-	mergehead_ids, rebase_progress,
+	continue_pending_rebase, merge_base, merge_branch, merge_commit,
+	merge_has_unresolved_conflicts, merge_msg, mergehead_ids,
+	rebase_progress,
 };
 pub use rebase::rebase_branch;
 pub use remotes::{
-	get_default_remote, get_remotes, push::AsyncProgress,
-	tags::PushTagsProgress,
+	fill_commit_url_template, get_default_remote, get_remotes,
+	push::AsyncProgress, remote_url, tags::PushTagsProgress,
 };
 pub(crate) use repository::repo;
 pub use repository::{RepoPath, RepoPathRef};
 pub use reset::{reset_stage, reset_workdir};
+pub use squash::{range_subjects, squash_preview, SquashPreview};
 pub use staging::{discard_lines, stage_lines};
 pub use stash::{
 	get_stashes, stash_apply, stash_drop, stash_pop, stash_save,
@@ -81,7 +94,8 @@ pub use stash::{
 pub use state::{repo_state, RepoState};
 pub use status::is_workdir_clean;
 pub use tags::{
-	delete_tag, get_tags, get_tags_with_metadata, CommitTags, Tag,
+	delete_tag, describe_commit, edit_tag_annotation, get_tags,
+	get_tags_with_metadata, CommitDescription, CommitTags, Tag,
 	TagWithMetadata, Tags,
 };
 pub use tree::{tree_file_content, tree_files, TreeFile};
@@ -89,9 +103,10 @@ pub use utils::{
 	get_head, get_head_tuple, is_repo, repo_dir, stage_add_all,
 	stage_add_file, stage_addremoved, Head,
 };
+pub use worktree::add_worktree;
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
 	use super::{
 		commit,
 		repository::repo,
@@ -20,7 +20,7 @@ use utils::bytes2string;
 pub use callbacks::Callbacks;
 pub use tags::tags_missing_remote;
 
-use super::RepoPath;
+use super::{CommitId, RepoPath};
 
 /// origin
 pub const DEFAULT_REMOTE_NAME: &str = "origin";
@@ -85,6 +85,120 @@ pub(crate) fn get_default_remote_in_repo(
 	Err(Error::NoDefaultRemoteFound)
 }
 
+/// returns the website of `remote` or of the default remote
+/// (see `get_default_remote`) if `None`
+///
+/// e.g. `https://github.com/user/repo` for `git@github.com:user/repo.git`.
+/// `None` if no remote is set up or its url does not point to a website.
+/// errors if the chosen `remote` does not exist or has no url
+pub fn remote_url(
+	repo_path: &RepoPath,
+	remote: Option<&str>,
+) -> Result<Option<String>> {
+	scope_time!("remote_url");
+
+	let repo = repo(repo_path)?;
+
+	let name = match remote {
+		Some(remote) => remote.to_string(),
+		None => match get_default_remote_in_repo(&repo) {
+			Ok(remote) => remote,
+			Err(Error::NoDefaultRemoteFound) => return Ok(None),
+			Err(e) => return Err(e),
+		},
+	};
+
+	let found = repo.find_remote(&name).map_err(|e| {
+		Error::Generic(format!("remote '{}': {}", name, e))
+	})?;
+
+	match found.url() {
+		Some(url) => Ok(url_to_website(url)),
+		None if remote.is_some() => Err(Error::Generic(format!(
+			"remote '{}' has no url",
+			name
+		))),
+		None => Ok(None),
+	}
+}
+
+const HASH_PLACEHOLDER: &str = "{hash}";
+const REPO_PLACEHOLDER: &str = "{repo}";
+
+/// fills the placeholders of a custom commit url template
+///
+/// in e.g. `https://git.corp/{repo}/commit/{hash}` `{hash}` becomes
+/// the full hash of `id` and `{repo}` the path of the remote
+/// `website` (see `remote_url`), e.g. `user/repo`.
+/// errors if the template has no `{hash}` or needs a missing website
+pub fn fill_commit_url_template(
+	template: &str,
+	website: Option<&str>,
+	id: CommitId,
+) -> Result<String> {
+	if !template.contains(HASH_PLACEHOLDER) {
+		return Err(Error::Generic(format!(
+			"commit url template '{}' has no {} placeholder",
+			template, HASH_PLACEHOLDER
+		)));
+	}
+
+	let mut url = template.replace(HASH_PLACEHOLDER, &id.to_string());
+
+	if url.contains(REPO_PLACEHOLDER) {
+		let repo = website
+			.and_then(|website| website.splitn(4, '/').nth(3))
+			.ok_or_else(|| {
+				Error::Generic(format!(
+					"no remote website to fill {} with",
+					REPO_PLACEHOLDER
+				))
+			})?;
+
+		url = url.replace(REPO_PLACEHOLDER, repo);
+	}
+
+	Ok(url)
+}
+
+/// converts http(s), ssh and scp-like remote urls into the http(s)
+/// url of the repository website
+fn url_to_website(url: &str) -> Option<String> {
+	let url = url.trim_end_matches('/');
+	let url = url.strip_suffix(".git").unwrap_or(url);
+
+	let (scheme, host, path) =
+		if let Ok(parsed) = url::Url::parse(url) {
+			let scheme = match parsed.scheme() {
+				"http" => "http",
+				"https" | "ssh" | "git" => "https",
+				_ => return None,
+			};
+
+			(
+				scheme,
+				parsed.host_str()?.to_string(),
+				parsed.path().to_string(),
+			)
+		} else {
+			// scp-like syntax: `[user@]host:path`
+			let mut split = url.splitn(2, ':');
+			let host = split.next()?;
+			let path = split.next()?;
+			let host = host.rsplit('@').next()?;
+
+			("https", host.to_string(), path.to_string())
+		};
+
+	let path = path.trim_matches('/');
+
+	if host.is_empty() || path.is_empty() {
+		return None;
+	}
+
+	Some(format!("{}://{}/{}", scheme, host, path))
+}
+
 ///
 fn fetch_from_remote(
 	repo_path: &RepoPath,
@@ -202,6 +316,104 @@ mod tests {
 		fetch(repo_path, "master", None, None).unwrap();
 	}
 
+	#[test]
+	fn test_url_to_website() {
+		assert_eq!(
+			url_to_website("git@github.com:user/repo.git").as_deref(),
+			Some("https://github.com/user/repo")
+		);
+		assert_eq!(
+			url_to_website("https://github.com/user/repo.git")
+				.as_deref(),
+			Some("https://github.com/user/repo")
+		);
+		assert_eq!(
+			url_to_website("http://host.local/group/repo/")
+				.as_deref(),
+			Some("http://host.local/group/repo")
+		);
+		assert_eq!(
+			url_to_website(
+				"ssh://git@gitlab.com:2222/group/repo.git"
+			)
+			.as_deref(),
+			Some("https://gitlab.com/group/repo")
+		);
+		assert_eq!(url_to_website("/tmp/some/repo"), None);
+		assert_eq!(url_to_website("file:///tmp/some/repo"), None);
+	}
+
+	#[test]
+	fn test_fill_commit_url_template() {
+		let id = CommitId::from_str_unchecked(
+			"0000000000000000000000000000000000000001",
+		)
+		.unwrap();
+		let website = Some("https://git.corp/group/repo");
+
+		assert_eq!(
+			fill_commit_url_template(
+				"https://git.corp/{repo}/commit/{hash}",
+				website,
+				id
+			)
+			.unwrap(),
+			"https://git.corp/group/repo/commit/0000000000000000000000000000000000000001"
+		);
+		assert_eq!(
+			fill_commit_url_template("https://x/{hash}", None, id)
+				.unwrap(),
+			"https://x/0000000000000000000000000000000000000001"
+		);
+
+		assert!(fill_commit_url_template(
+			"https://x/{repo}",
+			website,
+			id
+		)
+		.is_err());
+		assert!(fill_commit_url_template("{repo}/{hash}", None, id)
+			.is_err());
+	}
+
+	#[test]
+	fn test_remote_url() {
+		let (remote_dir, _remote) = repo_init().unwrap();
+		let remote_path = remote_dir.path().to_str().unwrap();
+		let (repo_dir, _repo) = repo_clone(remote_path).unwrap();
+		let repo_path: &RepoPath = &repo_dir
+			.into_path()
+			.as_os_str()
+			.to_str()
+			.unwrap()
+			.into();
+
+		assert_eq!(remote_url(repo_path, None).unwrap(), None);
+
+		debug_cmd_print(
+			repo_path,
+			"git remote set-url origin git@github.com:user/repo.git",
+		);
+
+		assert_eq!(
+			remote_url(repo_path, None).unwrap().as_deref(),
+			Some("https://github.com/user/repo")
+		);
+
+		debug_cmd_print(
+			repo_path,
+			"git remote add upstream https://example.com/org/repo",
+		);
+
+		assert_eq!(
+			remote_url(repo_path, Some("upstream"))
+				.unwrap()
+				.as_deref(),
+			Some("https://example.com/org/repo")
+		);
+		assert!(remote_url(repo_path, Some("missing")).is_err());
+	}
+
 	#[test]
 	fn test_default_remote() {
 		let (remote_dir, _remote) = repo_init().unwrap();
@@ -0,0 +1,210 @@
+//! read-only preview of squashing a range of commits
+
+use super::{CommitId, CommitLineStats, RepoPath};
+use crate::{
+	error::{Error, Result},
+	sync::repository::repo,
+	StatusItem, StatusItemType,
+};
+use git2::{Repository, Sort};
+use scopetime::scope_time;
+
+/// what squashing a range of commits into one would result in
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SquashPreview {
+	/// draft message: the subjects of the squashed commits,
+	/// oldest first
+	pub message: String,
+	/// number of commits squashed
+	pub count: usize,
+	/// files changed by the range as a whole
+	pub files: Vec<StatusItem>,
+	/// line stats of the combined diff
+	pub stats: CommitLineStats,
+}
+
+/// previews squashing all commits from the older of `ids` up to
+/// (and including) the newer one, nothing is changed in the repo.
+/// errors if neither commit is an ancestor of the other.
+pub fn squash_preview(
+	repo_path: &RepoPath,
+	ids: (CommitId, CommitId),
+) -> Result<SquashPreview> {
+	scope_time!("squash_preview");
+
+	let repo = repo(repo_path)?;
+	let (oldest, newest) = order_range(&repo, ids)?;
+	let subjects = subjects_in_range(&repo, oldest, newest)?;
+
+	let oldest = repo.find_commit(oldest.into())?;
+	let newest = repo.find_commit(newest.into())?;
+
+	let base = match oldest.parent(0) {
+		Ok(parent) => Some(parent.tree()?),
+		Err(_) => None,
+	};
+	let diff = repo.diff_tree_to_tree(
+		base.as_ref(),
+		Some(&newest.tree()?),
+		None,
+	)?;
+	let stats = diff.stats()?;
+
+	let files = diff
+		.deltas()
+		.map(|delta| StatusItem {
+			path: delta
+				.new_file()
+				.path()
+				.map(|p| p.to_str().unwrap_or("").to_string())
+				.unwrap_or_default(),
+			status: StatusItemType::from(delta.status()),
+		})
+		.collect();
+
+	Ok(SquashPreview {
+		message: subjects.join("\n"),
+		count: subjects.len(),
+		files,
+		stats: CommitLineStats {
+			files: stats.files_changed(),
+			added: stats.insertions(),
+			removed: stats.deletions(),
+		},
+	})
+}
+
+/// untruncated subjects of all commits from the older of `ids` up to
+/// (and including) the newer one, e.g. to draft release notes.
+/// errors if neither commit is an ancestor of the other.
+pub fn range_subjects(
+	repo_path: &RepoPath,
+	ids: (CommitId, CommitId),
+	oldest_first: bool,
+) -> Result<Vec<String>> {
+	scope_time!("range_subjects");
+
+	let repo = repo(repo_path)?;
+	let (oldest, newest) = order_range(&repo, ids)?;
+	let mut subjects = subjects_in_range(&repo, oldest, newest)?;
+
+	if !oldest_first {
+		subjects.reverse();
+	}
+
+	Ok(subjects)
+}
+
+/// subjects from `oldest` to `newest`, oldest first
+fn subjects_in_range(
+	repo: &Repository,
+	oldest: CommitId,
+	newest: CommitId,
+) -> Result<Vec<String>> {
+	let oldest = repo.find_commit(oldest.into())?;
+
+	let mut walk = repo.revwalk()?;
+	walk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)?;
+	walk.push(newest.into())?;
+	for parent in oldest.parent_ids() {
+		walk.hide(parent)?;
+	}
+
+	let mut subjects = Vec::new();
+	for id in walk {
+		let commit = repo.find_commit(id?)?;
+		subjects.push(
+			commit.summary().map(String::from).unwrap_or_default(),
+		);
+	}
+
+	Ok(subjects)
+}
+
+/// `(older, newer)` if one commit is an ancestor of the other
+fn order_range(
+	repo: &Repository,
+	ids: (CommitId, CommitId),
+) -> Result<(CommitId, CommitId)> {
+	let (a, b) = ids;
+
+	if a == b || repo.graph_descendant_of(b.into(), a.into())? {
+		Ok((a, b))
+	} else if repo.graph_descendant_of(a.into(), b.into())? {
+		Ok((b, a))
+	} else {
+		Err(Error::Generic(String::from(
+			"commits are not on one line of history",
+		)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::tests::{repo_init_empty, write_commit_file};
+
+	#[test]
+	fn test_squash_preview() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let c1 = write_commit_file(&repo, "a.txt", "a\n", "first");
+		write_commit_file(&repo, "b.txt", "b\n", "second");
+		let c3 = write_commit_file(&repo, "a.txt", "a\nc\n", "third");
+
+		let preview = squash_preview(repo_path, (c3, c1)).unwrap();
+
+		assert_eq!(preview.message, "first\nsecond\nthird");
+		assert_eq!(preview.count, 3);
+		assert_eq!(preview.files.len(), 2);
+		assert_eq!(preview.stats.added, 3);
+		assert_eq!(preview.stats.removed, 0);
+
+		let preview = squash_preview(repo_path, (c3, c3)).unwrap();
+		assert_eq!(preview.message, "third");
+		assert_eq!(preview.stats.added, 1);
+	}
+
+	#[test]
+	fn test_range_subjects() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		write_commit_file(&repo, "a.txt", "a", "before");
+		let c1 = write_commit_file(&repo, "a.txt", "b", "first");
+		let c2 =
+			write_commit_file(&repo, "a.txt", "c", "second\n\nbody");
+
+		assert_eq!(
+			range_subjects(repo_path, (c1, c2), false).unwrap(),
+			vec!["second", "first"]
+		);
+		assert_eq!(
+			range_subjects(repo_path, (c2, c1), true).unwrap(),
+			vec!["first", "second"]
+		);
+	}
+
+	#[test]
+	fn test_squash_preview_diverged() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let base = write_commit_file(&repo, "a.txt", "a", "base");
+		let c1 = write_commit_file(&repo, "a.txt", "b", "one");
+
+		let base_commit = repo.find_commit(base.into()).unwrap();
+		repo.branch("other", &base_commit, false).unwrap();
+		repo.set_head("refs/heads/other").unwrap();
+		let c2 = write_commit_file(&repo, "b.txt", "c", "two");
+
+		assert!(squash_preview(repo_path, (c1, c2)).is_err());
+	}
+}
@@ -1,8 +1,12 @@
 use super::{get_commits_info, CommitId, RepoPath};
 use crate::{
-	error::Result,
-	sync::{repository::repo, utils::bytes2string},
+	error::{Error, Result},
+	sync::{
+		commit::signature_allow_undefined_name, repository::repo,
+		utils::bytes2string,
+	},
 };
+use git2::{DescribeFormatOptions, DescribeOptions, ErrorClass};
 use scopetime::scope_time;
 use std::{
 	collections::{BTreeMap, HashMap, HashSet},
@@ -49,6 +53,17 @@ pub struct TagWithMetadata {
 	pub annotation: Option<String>,
 }
 
+/// result of `describe_commit`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommitDescription {
+	/// like `git describe --tags`, e.g. `v1.2.0-5-gabc1234`
+	pub description: String,
+	/// name of the nearest tag
+	pub tag: String,
+	/// commit the nearest tag points to
+	pub tag_commit: CommitId,
+}
+
 static MAX_MESSAGE_WIDTH: usize = 100;
 
 /// returns `Tags` type filled with all tags found in repo
@@ -184,10 +199,92 @@ pub fn delete_tag(
 	Ok(())
 }
 
+/// replaces the message of the annotated tag `tag_name` by
+/// recreating it with the same name and target (like `git tag -f -a`).
+/// errors if the tag is lightweight or no longer points to `commit_id`
+pub fn edit_tag_annotation(
+	repo_path: &RepoPath,
+	tag_name: &str,
+	commit_id: CommitId,
+	message: &str,
+) -> Result<()> {
+	scope_time!("edit_tag_annotation");
+
+	if message.is_empty() {
+		return Err(Error::Generic(
+			"tag message cannot be empty".to_string(),
+		));
+	}
+
+	let repo = repo(repo_path)?;
+	let tag = repo
+		.find_reference(&format!("refs/tags/{}", tag_name))?
+		.peel_to_tag()
+		.map_err(|_| {
+			Error::Generic(format!(
+				"'{}' is not an annotated tag",
+				tag_name
+			))
+		})?;
+
+	let target = tag.target()?;
+	if CommitId::new(target.peel_to_commit()?.id()) != commit_id {
+		return Err(Error::Generic(format!(
+			"tag '{}' no longer points to {}",
+			tag_name,
+			commit_id.get_short_string()
+		)));
+	}
+
+	let signature = signature_allow_undefined_name(&repo)?;
+	repo.tag(tag_name, &target, &signature, message, true)?;
+
+	Ok(())
+}
+
+/// nearest tag reachable from `id` (like `git describe --tags`),
+/// `None` if no tag describes it
+pub fn describe_commit(
+	repo_path: &RepoPath,
+	id: CommitId,
+) -> Result<Option<CommitDescription>> {
+	scope_time!("describe_commit");
+
+	let repo = repo(repo_path)?;
+	let commit = repo.find_commit(id.into())?;
+
+	let describe = match commit
+		.as_object()
+		.describe(DescribeOptions::new().describe_tags())
+	{
+		Ok(describe) => describe,
+		// no tag to describe it with
+		Err(e) if e.class() == ErrorClass::Describe => {
+			return Ok(None)
+		}
+		Err(e) => return Err(e.into()),
+	};
+
+	let description =
+		describe.format(Some(&DescribeFormatOptions::new()))?;
+	// like `--abbrev=0`: only the tag name
+	let tag = describe.format(Some(
+		DescribeFormatOptions::new().abbreviated_size(0),
+	))?;
+	let tag_commit =
+		repo.revparse_single(&tag)?.peel_to_commit()?.id().into();
+
+	Ok(Some(CommitDescription {
+		description,
+		tag,
+		tag_commit,
+	}))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::sync::tests::repo_init;
+	use crate::sync::tests::{repo_init, write_commit_file};
 	use git2::ObjectType;
 
 	#[test]
@@ -248,4 +345,76 @@ mod tests {
 
 		assert_eq!(tags.len(), 0);
 	}
+
+	#[test]
+	fn test_edit_tag_annotation() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let head_id =
+			CommitId::new(repo.head().unwrap().target().unwrap());
+		let sig = repo.signature().unwrap();
+		let target = repo
+			.find_object(head_id.into(), Some(ObjectType::Commit))
+			.unwrap();
+		repo.tag("a", &target, &sig, "old", false).unwrap();
+		repo.tag_lightweight("b", &target, false).unwrap();
+
+		edit_tag_annotation(repo_path, "a", head_id, "new").unwrap();
+
+		let tags = get_tags(repo_path).unwrap();
+		assert_eq!(tags[&head_id][0].name, "a");
+		assert_eq!(
+			tags[&head_id][0].annotation.as_deref(),
+			Some("new")
+		);
+
+		assert!(
+			edit_tag_annotation(repo_path, "a", head_id, "").is_err()
+		);
+		assert!(edit_tag_annotation(repo_path, "b", head_id, "x")
+			.is_err());
+
+		let c2 = write_commit_file(&repo, "foo", "a", "c2");
+		assert!(edit_tag_annotation(repo_path, "a", c2, "x").is_err());
+	}
+
+	#[test]
+	fn test_describe_commit() {
+		let (_td, repo) = repo_init().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let head_id =
+			CommitId::new(repo.head().unwrap().target().unwrap());
+
+		assert_eq!(
+			describe_commit(repo_path, head_id).unwrap(),
+			None
+		);
+
+		let sig = repo.signature().unwrap();
+		let target = repo
+			.find_object(head_id.into(), Some(ObjectType::Commit))
+			.unwrap();
+		repo.tag("v1.0", &target, &sig, "", false).unwrap();
+
+		let c2 = write_commit_file(&repo, "foo", "a", "c2");
+
+		let desc =
+			describe_commit(repo_path, head_id).unwrap().unwrap();
+		assert_eq!(desc.description, "v1.0");
+		assert_eq!(desc.tag_commit, head_id);
+
+		let desc = describe_commit(repo_path, c2).unwrap().unwrap();
+		assert_eq!(
+			desc.description,
+			format!("v1.0-1-g{}", c2.get_short_string())
+		);
+		assert_eq!(desc.tag, "v1.0");
+		assert_eq!(desc.tag_commit, head_id);
+	}
 }
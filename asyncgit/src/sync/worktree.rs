@@ -0,0 +1,169 @@
+//! git worktrees
+
+use super::{CommitId, RepoPath};
+use crate::{
+	error::{Error, Result},
+	sync::repository::repo,
+};
+use git2::{BranchType, WorktreeAddOptions};
+use scopetime::scope_time;
+use std::path::Path;
+
+/// creates a worktree at `path` checked out at `id`
+///
+/// a relative `path` is relative to the workdir of the repo.
+/// like `git worktree add` this creates a new branch named after
+/// the last component of `path` pointing to `id`.
+/// errors if `path` or the branch already exist, the branch is
+/// removed again if the worktree cannot be created.
+pub fn add_worktree(
+	repo_path: &RepoPath,
+	id: CommitId,
+	path: &str,
+) -> Result<()> {
+	scope_time!("add_worktree");
+
+	let repo = repo(repo_path)?;
+
+	let path = repo.workdir().map_or_else(
+		|| Path::new(path).to_path_buf(),
+		|workdir| workdir.join(path),
+	);
+	if path.exists() {
+		return Err(Error::Generic(format!(
+			"'{}' already exists",
+			path.display()
+		)));
+	}
+
+	let name = path
+		.file_name()
+		.and_then(|name| name.to_str())
+		.ok_or_else(|| {
+			Error::Generic(format!(
+				"'{}' has no valid worktree name",
+				path.display()
+			))
+		})?;
+
+	if repo.find_branch(name, BranchType::Local).is_ok() {
+		return Err(Error::Generic(format!(
+			"branch '{}' already exists",
+			name
+		)));
+	}
+
+	let commit = repo.find_commit(id.into())?;
+	let mut branch = repo.branch(name, &commit, false)?;
+
+	let mut opts = WorktreeAddOptions::new();
+	opts.reference(Some(branch.get()));
+
+	if let Err(e) = repo.worktree(name, &path, Some(&opts)) {
+		if let Err(e) = branch.delete() {
+			log::error!("failed to remove worktree branch: {}", e);
+		}
+		return Err(e.into());
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sync::{
+		get_head,
+		tests::{repo_init_empty, write_commit_file},
+	};
+	use tempfile::TempDir;
+
+	#[test]
+	fn test_add_worktree() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let first = write_commit_file(&repo, "a.txt", "a", "first");
+		write_commit_file(&repo, "a.txt", "b", "second");
+
+		let td = TempDir::new().unwrap();
+		let path = td.path().join("wt");
+		let path = path.to_str().unwrap();
+
+		add_worktree(repo_path, first, path).unwrap();
+
+		assert_eq!(get_head(&path.into()).unwrap(), first);
+		assert_eq!(
+			std::fs::read_to_string(Path::new(path).join("a.txt"))
+				.unwrap(),
+			"a"
+		);
+
+		assert!(add_worktree(repo_path, first, path).is_err());
+	}
+
+	#[test]
+	fn test_add_worktree_relative() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let first = write_commit_file(&repo, "a.txt", "a", "first");
+
+		add_worktree(repo_path, first, "wt").unwrap();
+
+		assert!(root.join("wt").join("a.txt").exists());
+	}
+
+	#[test]
+	fn test_add_worktree_branch_taken() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let first = write_commit_file(&repo, "a.txt", "a", "first");
+		repo.branch(
+			"wt",
+			&repo.find_commit(first.into()).unwrap(),
+			false,
+		)
+		.unwrap();
+
+		let td = TempDir::new().unwrap();
+		let path = td.path().join("wt");
+
+		assert!(add_worktree(
+			repo_path,
+			first,
+			path.to_str().unwrap()
+		)
+		.is_err());
+		assert!(!path.exists());
+	}
+
+	#[test]
+	fn test_add_worktree_failed_keeps_no_branch() {
+		let (_td, repo) = repo_init_empty().unwrap();
+		let root = repo.path().parent().unwrap();
+		let repo_path: &RepoPath =
+			&root.as_os_str().to_str().unwrap().into();
+
+		let first = write_commit_file(&repo, "a.txt", "a", "first");
+
+		// the parent of the worktree has to exist
+		let td = TempDir::new().unwrap();
+		let path = td.path().join("missing").join("wt");
+
+		assert!(add_worktree(
+			repo_path,
+			first,
+			path.to_str().unwrap()
+		)
+		.is_err());
+		assert!(repo.find_branch("wt", BranchType::Local).is_err());
+	}
+}
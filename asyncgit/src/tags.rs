@@ -16,7 +16,7 @@ use sync::Tags;
 #[derive(Default, Clone)]
 pub struct TagsResult {
 	hash: u64,
-	tags: Tags,
+	tags: Arc<Tags>,
 }
 
 ///
@@ -41,9 +41,12 @@ impl AsyncTags {
 		}
 	}
 
-	/// last fetched result
-	pub fn last(&self) -> Result<Option<Tags>> {
-		Ok(self.last.as_ref().map(|result| result.1.tags.clone()))
+	/// last fetched result, shared instead of deep cloned
+	pub fn last(&self) -> Result<Option<Arc<Tags>>> {
+		Ok(self
+			.last
+			.as_ref()
+			.map(|result| Arc::clone(&result.1.tags)))
 	}
 
 	///
@@ -157,7 +160,13 @@ impl AsyncJob for AsyncTagsJob {
 							notification = AsyncGitNotification::Tags;
 						}
 
-						(Instant::now(), TagsResult { hash, tags })
+						(
+							Instant::now(),
+							TagsResult {
+								hash,
+								tags: Arc::new(tags),
+							},
+						)
 					}))
 				}
 				JobState::Response(result) => {
@@ -4,21 +4,28 @@ use crate::{
 	components::{
 		event_pump, AppOption, BlameFileComponent,
 		BranchListComponent, CommandBlocking, CommandInfo,
-		CommitComponent, CompareCommitsComponent, Component,
-		ConfirmComponent, CreateBranchComponent, DrawableComponent,
-		ExternalEditorComponent, FetchComponent, FileFindPopup,
-		FileRevlogComponent, HelpComponent, InspectCommitComponent,
+		CommandPaletteComponent, CommitComponent,
+		CompareCommitsComponent, Component, ConfirmComponent,
+		CreateBranchComponent, CreateWorktreeComponent,
+		DrawableComponent, EditTagComponent, ExternalEditorComponent,
+		FetchComponent, FileFindPopup, FileRevlogComponent,
+		GotoCommitComponent, HelpComponent, InspectCommitComponent,
+		LogColumnsPopupComponent, MergeBasePopupComponent,
 		MsgComponent, OptionsPopupComponent, PullComponent,
-		PushComponent, PushTagsComponent, RenameBranchComponent,
-		RevisionFilesPopup, SharedOptions, StashMsgComponent,
+		PushComponent, PushTagsComponent,
+		RecentCommitsPopupComponent, RemoteListPopupComponent,
+		RenameBranchComponent, RevisionFilesPopup, SharedOptions,
+		SquashPreviewComponent, StashMsgComponent,
 		TagCommitComponent, TagListComponent,
 	},
 	input::{Input, InputEvent, InputState},
 	keys::{KeyConfig, SharedKeyConfig},
+	log_columns::LogColumns,
 	popup_stack::PopupStack,
 	queue::{
 		Action, InternalEvent, NeedsUpdate, Queue, StackablePopupOpen,
 	},
+	session::Session,
 	setup_popups,
 	strings::{self, order},
 	tabs::{FilesTab, Revlog, StashList, Stashing, Status},
@@ -27,7 +34,7 @@ use crate::{
 };
 use anyhow::{bail, Result};
 use asyncgit::{
-	sync::{self, RepoPathRef},
+	sync::{self, CommitId, RepoPathRef},
 	AsyncGitNotification, PushType,
 };
 use crossbeam_channel::Sender;
@@ -70,6 +77,15 @@ pub struct App {
 	rename_branch_popup: RenameBranchComponent,
 	select_branch_popup: BranchListComponent,
 	options_popup: OptionsPopupComponent,
+	log_columns_popup: LogColumnsPopupComponent,
+	recent_commits_popup: RecentCommitsPopupComponent,
+	goto_commit_popup: GotoCommitComponent,
+	command_palette: CommandPaletteComponent,
+	squash_preview_popup: SquashPreviewComponent,
+	create_worktree_popup: CreateWorktreeComponent,
+	edit_tag_popup: EditTagComponent,
+	remote_list_popup: RemoteListPopupComponent,
+	merge_base_popup: MergeBasePopupComponent,
 	tags_popup: TagListComponent,
 	cmdbar: RefCell<CommandBar>,
 	tab: usize,
@@ -100,13 +116,15 @@ impl App {
 		input: Input,
 		theme: Theme,
 		key_config: KeyConfig,
+		log_columns: LogColumns,
 	) -> Self {
 		let queue = Queue::new();
 		let theme = Rc::new(theme);
 		let key_config = Rc::new(key_config);
 		let options = SharedOptions::default();
+		let log_columns = Rc::new(RefCell::new(log_columns));
 
-		Self {
+		let mut app = Self {
 			input,
 			reset: ConfirmComponent::new(
 				queue.clone(),
@@ -225,6 +243,57 @@ impl App {
 				theme.clone(),
 				key_config.clone(),
 			),
+			log_columns_popup: LogColumnsPopupComponent::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+				log_columns.clone(),
+			),
+			recent_commits_popup: RecentCommitsPopupComponent::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			goto_commit_popup: GotoCommitComponent::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			command_palette: CommandPaletteComponent::new(
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			squash_preview_popup: SquashPreviewComponent::new(
+				repo.clone(),
+				theme.clone(),
+				key_config.clone(),
+			),
+			create_worktree_popup: CreateWorktreeComponent::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			edit_tag_popup: EditTagComponent::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			remote_list_popup: RemoteListPopupComponent::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
+			merge_base_popup: MergeBasePopupComponent::new(
+				repo.clone(),
+				&queue,
+				theme.clone(),
+				key_config.clone(),
+			),
 			options_popup: OptionsPopupComponent::new(
 				&queue,
 				theme.clone(),
@@ -253,6 +322,7 @@ impl App {
 				sender,
 				theme.clone(),
 				key_config.clone(),
+				log_columns,
 			),
 			status_tab: Status::new(
 				repo.clone(),
@@ -289,7 +359,11 @@ impl App {
 			file_to_open: None,
 			repo,
 			popup_stack: PopupStack::default(),
-		}
+		};
+
+		app.restore_session();
+
+		app
 	}
 
 	///
@@ -465,6 +539,21 @@ impl App {
 		Ok(())
 	}
 
+	/// remembers tab and log selection for the next start
+	pub fn save_session(&self) {
+		let session = Session {
+			tab: self.tab,
+			selected_commit: self
+				.revlog
+				.last_selected_commit()
+				.map(|id| id.to_string()),
+		};
+
+		if let Err(e) = session.save(&self.repo.borrow()) {
+			log::error!("failed to save session: {}", e);
+		}
+	}
+
 	///
 	pub fn is_quit(&self) -> bool {
 		self.do_quit || self.input.is_aborted()
@@ -526,6 +615,15 @@ impl App {
 			revision_files_popup,
 			tags_popup,
 			options_popup,
+			log_columns_popup,
+			recent_commits_popup,
+			goto_commit_popup,
+			command_palette,
+			squash_preview_popup,
+			create_worktree_popup,
+			edit_tag_popup,
+			remote_list_popup,
+			merge_base_popup,
 			help,
 			revlog,
 			status_tab,
@@ -558,6 +656,15 @@ impl App {
 			pull_popup,
 			fetch_popup,
 			options_popup,
+			log_columns_popup,
+			recent_commits_popup,
+			goto_commit_popup,
+			command_palette,
+			squash_preview_popup,
+			create_worktree_popup,
+			edit_tag_popup,
+			remote_list_popup,
+			merge_base_popup,
 			reset,
 			msg
 		]
@@ -586,6 +693,23 @@ impl App {
 		false
 	}
 
+	fn restore_session(&mut self) {
+		let session = Session::load(&self.repo.borrow());
+
+		if let Some(id) = session
+			.selected_commit
+			.and_then(|id| CommitId::from_str_unchecked(&id).ok())
+		{
+			self.revlog.select_commit_once_loaded(id);
+		}
+
+		if session.tab < self.get_tabs().len() {
+			if let Err(e) = self.set_tab(session.tab) {
+				log::error!("failed to restore tab: {}", e);
+			}
+		}
+	}
+
 	fn get_tabs(&mut self) -> Vec<&mut dyn Component> {
 		vec![
 			&mut self.status_tab,
@@ -686,6 +810,7 @@ impl App {
 				self.revision_files_popup.open(param)?;
 			}
 			StackablePopupOpen::InspectCommit(param) => {
+				self.recent_commits_popup.push(param.commit_id);
 				self.inspect_commit_popup.open(param)?;
 			}
 			StackablePopupOpen::CompareCommits(param) => {
@@ -738,6 +863,9 @@ impl App {
 			}
 			InternalEvent::Update(u) => flags.insert(u),
 			InternalEvent::OpenCommit => self.commit.show()?,
+			InternalEvent::OpenAmendCommit => {
+				self.commit.show_amend()?;
+			}
 			InternalEvent::PopupStashing(opts) => {
 				self.stashmsg_popup.options(opts);
 				self.stashmsg_popup.show()?;
@@ -745,6 +873,38 @@ impl App {
 			InternalEvent::TagCommit(id) => {
 				self.tag_commit_popup.open(id)?;
 			}
+			InternalEvent::CreateWorktree(id) => {
+				self.create_worktree_popup.open(id)?;
+			}
+			InternalEvent::EditTag(id, name, annotation) => {
+				self.edit_tag_popup.open(id, name, annotation)?;
+			}
+			InternalEvent::TagsChanged => {
+				self.revlog.reload_tags()?;
+				flags.insert(NeedsUpdate::ALL);
+			}
+			InternalEvent::SelectUrlRemote => {
+				self.remote_list_popup.open()?;
+			}
+			InternalEvent::UrlRemoteSelected(remote) => {
+				if let Err(e) = self.revlog.set_url_remote(remote) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("copy error:\n{}", e),
+					));
+				}
+			}
+			InternalEvent::SelectMergeBaseBranch => {
+				self.merge_base_popup.open()?;
+			}
+			InternalEvent::GotoMergeBase(branch, id) => {
+				if let Err(e) =
+					self.revlog.goto_merge_base(&branch, id)
+				{
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("merge-base error:\n{}", e),
+					));
+				}
+			}
 
 			InternalEvent::CreateBranch => {
 				self.create_branch_popup.open()?;
@@ -823,9 +983,44 @@ impl App {
 
 				flags.insert(NeedsUpdate::ALL);
 			}
+			InternalEvent::OpenLogColumns => {
+				self.log_columns_popup.show()?;
+			}
+			InternalEvent::OpenRecentCommits => {
+				self.recent_commits_popup.open()?;
+			}
+			InternalEvent::OpenGotoCommit => {
+				self.goto_commit_popup.show()?;
+			}
+			InternalEvent::GotoCommitPosition(position) => {
+				self.revlog.goto_position(position)?;
+				flags.insert(NeedsUpdate::ALL);
+			}
+			InternalEvent::OpenLogCommandPalette => {
+				let mut commands = Vec::new();
+				self.revlog.commands(&mut commands, false);
+				self.command_palette.open(commands)?;
+			}
+			InternalEvent::RunLogCommand(key) => {
+				self.revlog.event(Event::Key(key))?;
+				flags.insert(NeedsUpdate::ALL);
+			}
+			InternalEvent::OpenSquashPreview(ids) => {
+				if let Err(e) = self.squash_preview_popup.open(ids) {
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("squash preview error:\n{}", e),
+					));
+				}
+			}
+			InternalEvent::LogColumnsChanged => {
+				self.revlog.columns_changed();
+				flags.insert(NeedsUpdate::ALL);
+			}
 			InternalEvent::FileFinderChanged(file) => {
 				self.files_tab.file_finder_update(&file);
 				self.revision_files_popup.file_finder_update(&file);
+				self.revlog.file_finder_update(&file);
+				self.inspect_commit_popup.file_finder_update(&file);
 				flags
 					.insert(NeedsUpdate::ALL | NeedsUpdate::COMMANDS);
 			}
@@ -971,6 +1166,18 @@ impl App {
 				self.status_tab.abort_rebase();
 				flags.insert(NeedsUpdate::ALL);
 			}
+			Action::RevertCommit(id) => {
+				if let Err(e) =
+					sync::revert_commit(&self.repo.borrow(), id)
+				{
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("revert error:\n{}", e),
+					));
+				} else {
+					self.queue.push(InternalEvent::TabSwitchStatus);
+				}
+				flags.insert(NeedsUpdate::ALL);
+			}
 		};
 
 		Ok(())
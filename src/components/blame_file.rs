@@ -13,7 +13,7 @@ use crate::{
 };
 use anyhow::Result;
 use asyncgit::{
-	sync::{BlameHunk, CommitId, FileBlame, RepoPathRef},
+	sync::{self, BlameHunk, CommitId, FileBlame, RepoPathRef},
 	AsyncBlame, AsyncGitNotification, BlameParams,
 };
 use crossbeam_channel::Sender;
@@ -41,6 +41,7 @@ pub struct BlameFileOpen {
 }
 
 pub struct BlameFileComponent {
+	repo: RepoPathRef,
 	title: String,
 	theme: SharedTheme,
 	queue: Queue,
@@ -49,6 +50,8 @@ pub struct BlameFileComponent {
 	open_request: Option<BlameFileOpen>,
 	params: Option<BlameParams>,
 	file_blame: Option<FileBlame>,
+	/// time of the blamed commit while the age heatmap is shown
+	heatmap: Option<i64>,
 	table_state: std::cell::Cell<TableState>,
 	key_config: SharedKeyConfig,
 	current_height: std::cell::Cell<usize>,
@@ -176,6 +179,16 @@ impl Component for BlameFileComponent {
 				)
 				.order(1),
 			);
+			out.push(
+				CommandInfo::new(
+					strings::commands::toggle_blame_heatmap(
+						&self.key_config,
+					),
+					true,
+					self.file_blame.is_some(),
+				)
+				.order(1),
+			);
 		}
 
 		visibility_blocking(self)
@@ -227,6 +240,8 @@ impl Component for BlameFileComponent {
 							),
 						));
 					}
+				} else if key == self.key_config.keys.blame_heatmap {
+					self.toggle_heatmap();
 				}
 
 				return Ok(EventState::Consumed);
@@ -258,6 +273,7 @@ impl BlameFileComponent {
 		key_config: SharedKeyConfig,
 	) -> Self {
 		Self {
+			repo: repo.clone(),
 			title: String::from(title),
 			theme,
 			async_blame: AsyncBlame::new(
@@ -268,6 +284,7 @@ impl BlameFileComponent {
 			visible: false,
 			params: None,
 			file_blame: None,
+			heatmap: None,
 			open_request: None,
 			table_state: std::cell::Cell::new(TableState::default()),
 			key_config,
@@ -300,6 +317,7 @@ impl BlameFileComponent {
 			commit_id: open.commit_id,
 		});
 		self.file_blame = None;
+		self.heatmap = None;
 		self.table_state.get_mut().select(Some(0));
 		self.visible = true;
 
@@ -348,6 +366,20 @@ impl BlameFileComponent {
 		Ok(())
 	}
 
+	fn toggle_heatmap(&mut self) {
+		if self.heatmap.take().is_none() {
+			self.heatmap =
+				self.file_blame.as_ref().and_then(|blame| {
+					sync::get_commit_info(
+						&self.repo.borrow(),
+						&blame.commit_id,
+					)
+					.map(|info| info.time)
+					.ok()
+				});
+		}
+	}
+
 	///
 	fn get_title(&self) -> String {
 		match (
@@ -439,9 +471,15 @@ impl BlameFileComponent {
 			))
 			.style(self.theme.text(true, false)),
 		);
+		let line_style = match (self.heatmap, hunk_for_line) {
+			(Some(commit_time), Some(hunk)) => {
+				self.theme.blame_line_age(commit_time - hunk.time)
+			}
+			_ => self.theme.text(true, false),
+		};
 		cells.push(
 			Cell::from(tabs_to_spaces(String::from(line)))
-				.style(self.theme.text(true, false)),
+				.style(line_style),
 		);
 
 		Row::new(cells)
@@ -1,3 +1,5 @@
+use crossterm::event::KeyEvent;
+
 ///
 #[derive(Clone, PartialEq, PartialOrd, Ord, Eq)]
 pub struct CommandText {
@@ -46,6 +48,9 @@ pub struct CommandInfo {
 	pub available: bool,
 	/// used to order commands in quickbar
 	pub order: i8,
+	/// the key triggering the command, allows running it
+	/// from the command palette
+	pub key: Option<KeyEvent>,
 }
 
 impl CommandInfo {
@@ -61,6 +66,7 @@ impl CommandInfo {
 			quick_bar: true,
 			available,
 			order: 0,
+			key: None,
 		}
 	}
 
@@ -71,6 +77,13 @@ impl CommandInfo {
 		res
 	}
 
+	///
+	pub const fn key(self, key: KeyEvent) -> Self {
+		let mut res = self;
+		res.key = Some(key);
+		res
+	}
+
 	///
 	pub const fn hidden(self) -> Self {
 		let mut res = self;
@@ -0,0 +1,291 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType, TextInputComponent,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use crossterm::event::Event;
+use fuzzy_matcher::FuzzyMatcher;
+use std::borrow::Cow;
+use tui::{
+	backend::Backend,
+	layout::{Constraint, Direction, Layout, Margin, Rect},
+	text::{Span, Spans},
+	widgets::{Block, Borders, Clear},
+	Frame,
+};
+
+/// fuzzy searchable list of the commands of the log,
+/// running one sends its key to the log
+pub struct CommandPaletteComponent {
+	queue: Queue,
+	visible: bool,
+	find_text: TextInputComponent,
+	theme: SharedTheme,
+	commands: Vec<CommandInfo>,
+	/// indices into `commands` matching the query, best first
+	filtered: Vec<usize>,
+	selection: usize,
+	key_config: SharedKeyConfig,
+}
+
+impl CommandPaletteComponent {
+	///
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		let mut find_text = TextInputComponent::new(
+			theme.clone(),
+			key_config.clone(),
+			"",
+			"start typing..",
+			false,
+		);
+		find_text.embed();
+
+		Self {
+			queue: queue.clone(),
+			visible: false,
+			find_text,
+			theme,
+			commands: Vec::new(),
+			filtered: Vec::new(),
+			selection: 0,
+			key_config,
+		}
+	}
+
+	/// only commands that are available and bound to a key are listed
+	pub fn open(&mut self, commands: Vec<CommandInfo>) -> Result<()> {
+		self.commands = commands
+			.into_iter()
+			.filter(|cmd| cmd.available && cmd.key.is_some())
+			.collect();
+		self.find_text.set_text(String::new());
+		self.update_query();
+		self.find_text.show()?;
+		self.show()
+	}
+
+	fn update_query(&mut self) {
+		let query = self.find_text.get_text();
+
+		self.filtered = if query.is_empty() {
+			(0..self.commands.len()).collect()
+		} else {
+			let matcher =
+				fuzzy_matcher::skim::SkimMatcherV2::default();
+
+			let mut hits = self
+				.commands
+				.iter()
+				.enumerate()
+				.filter_map(|(idx, cmd)| {
+					matcher
+						.fuzzy_match(
+							&format!(
+								"{} {}",
+								cmd.text.name, cmd.text.desc
+							),
+							query,
+						)
+						.map(|score| (score, idx))
+				})
+				.collect::<Vec<_>>();
+
+			hits.sort_by(|(score1, _), (score2, _)| {
+				score2.cmp(score1)
+			});
+
+			hits.into_iter().map(|(_, idx)| idx).collect()
+		};
+
+		self.selection = 0;
+	}
+
+	fn move_selection(&mut self, move_type: ScrollType) {
+		let new_selection = match move_type {
+			ScrollType::Up => self.selection.saturating_sub(1),
+			ScrollType::Down => self.selection.saturating_add(1),
+			_ => self.selection,
+		};
+
+		self.selection =
+			new_selection.min(self.filtered.len().saturating_sub(1));
+	}
+
+	fn run_selected(&mut self) {
+		let key = self
+			.filtered
+			.get(self.selection)
+			.and_then(|idx| self.commands.get(*idx))
+			.filter(|cmd| cmd.enabled)
+			.and_then(|cmd| cmd.key);
+
+		if let Some(key) = key {
+			self.hide();
+			self.queue.push(InternalEvent::RunLogCommand(key));
+		}
+	}
+}
+
+impl DrawableComponent for CommandPaletteComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const MAX_SIZE: (u16, u16) = (70, 20);
+
+			let area = ui::centered_rect_absolute(
+				MAX_SIZE.0, MAX_SIZE.1, area,
+			);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Block::default()
+					.borders(Borders::all())
+					.style(self.theme.title(true))
+					.title(Span::styled(
+						strings::command_palette_title(),
+						self.theme.title(true),
+					)),
+				area,
+			);
+
+			let chunks = Layout::default()
+				.direction(Direction::Vertical)
+				.constraints(
+					[
+						Constraint::Length(1),
+						Constraint::Percentage(100),
+					]
+					.as_ref(),
+				)
+				.split(area.inner(&Margin {
+					horizontal: 1,
+					vertical: 1,
+				}));
+
+			self.find_text.draw(f, chunks[0])?;
+
+			let height = usize::from(chunks[1].height);
+			let skip = self
+				.selection
+				.saturating_sub(height.saturating_sub(2));
+
+			let items = self
+				.filtered
+				.iter()
+				.enumerate()
+				.skip(skip)
+				.take(height)
+				.map(|(idx, cmd_idx)| {
+					let cmd = &self.commands[*cmd_idx];
+					let selected = idx == self.selection;
+					Spans::from(vec![
+						Span::styled(
+							Cow::from(cmd.text.name.as_str()),
+							self.theme.text(cmd.enabled, selected),
+						),
+						Span::styled(
+							Cow::from(format!(
+								" - {}",
+								cmd.text.desc
+							)),
+							self.theme.text(false, selected),
+						),
+					])
+				});
+
+			ui::draw_list_block(
+				f,
+				chunks[1],
+				Block::default().borders(Borders::TOP),
+				items,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for CommandPaletteComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+
+			out.push(CommandInfo::new(
+				strings::commands::command_palette_run(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = &event {
+				if *key == self.key_config.keys.exit_popup {
+					self.hide();
+				} else if *key == self.key_config.keys.enter {
+					self.run_selected();
+				} else if *key == self.key_config.keys.popup_down {
+					self.move_selection(ScrollType::Down);
+				} else if *key == self.key_config.keys.popup_up {
+					self.move_selection(ScrollType::Up);
+				}
+			}
+
+			if self.find_text.event(event)?.is_consumed() {
+				self.update_query();
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+		Ok(())
+	}
+}
@@ -246,6 +246,22 @@ impl CommitComponent {
 		Ok(CommitResult::ComitDone)
 	}
 
+	/// opens the dialog in amend mode, prefilled with the HEAD message.
+	/// an unfinished commit message is kept instead and amending is
+	/// refused, like the amend key in the dialog does
+	pub fn show_amend(&mut self) -> Result<()> {
+		self.show()?;
+
+		if self.can_amend() {
+			self.amend()
+		} else {
+			self.queue.push(InternalEvent::ShowErrorMsg(
+				strings::commit_amend_draft_pending(),
+			));
+			Ok(())
+		}
+	}
+
 	fn can_commit(&self) -> bool {
 		!self.is_empty() && self.is_changed()
 	}
@@ -4,19 +4,27 @@ use crate::{
 		dialog_paragraph,
 		utils::{scroll_vertical::VerticalScroll, time_to_string},
 		CommandBlocking, CommandInfo, Component, DrawableComponent,
-		EventState, ScrollType,
+		EventState, InspectCommitOpen, ScrollType,
 	},
 	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue, StackablePopupOpen},
 	strings::{self, order},
-	ui::style::SharedTheme,
+	ui::{ansi_spans, strip_ansi, style::SharedTheme},
 };
 use anyhow::Result;
-use asyncgit::sync::{
-	self, CommitDetails, CommitId, CommitMessage, RepoPathRef, Tag,
+use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	line_stats::AsyncLineStatsJob,
+	sync::{
+		self, CommitDetails, CommitId, CommitLineStats,
+		CommitMessage, DiffParent, RepoPathRef, Tag,
+	},
+	AsyncGitNotification,
 };
+use crossbeam_channel::Sender;
 use crossterm::event::Event;
 use std::clone::Clone;
-use std::{borrow::Cow, cell::Cell};
+use std::{borrow::Cow, cell::Cell, collections::HashMap};
 use sync::CommitTags;
 use tui::{
 	backend::Backend,
@@ -28,15 +36,32 @@ use tui::{
 
 use super::style::Detail;
 
+/// diffstats kept before the cache starts over
+const STATS_CACHE_SIZE: usize = 100;
+
 pub struct DetailsComponent {
 	repo: RepoPathRef,
 	data: Option<CommitDetails>,
+	stats: Option<CommitLineStats>,
+	/// diffing is expensive, keep the stats of commits seen before
+	stats_cache: HashMap<(CommitId, DiffParent), CommitLineStats>,
+	/// the stats computed last, not in `stats_cache` until it is done
+	stats_requested: Option<(CommitId, DiffParent)>,
+	async_stats: AsyncSingleJob<AsyncLineStatsJob>,
 	tags: Vec<Tag>,
+	/// index into the parents of the commit, opened by `inspect_parent`
+	selected_parent: usize,
+	/// what the files of a merge commit are listed against
+	diff_parent: DiffParent,
+	queue: Queue,
 	theme: SharedTheme,
 	focused: bool,
 	current_width: Cell<u16>,
 	scroll: VerticalScroll,
 	scroll_to_bottom_next_draw: Cell<bool>,
+	/// color the message according to embedded ANSI sequences
+	/// instead of stripping them
+	interpret_ansi: bool,
 	key_config: SharedKeyConfig,
 }
 
@@ -45,8 +70,10 @@ type WrappedCommitMessage<'a> =
 
 impl DetailsComponent {
 	///
-	pub const fn new(
+	pub fn new(
 		repo: RepoPathRef,
+		queue: &Queue,
+		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
 		focused: bool,
@@ -54,12 +81,20 @@ impl DetailsComponent {
 		Self {
 			repo,
 			data: None,
+			stats: None,
+			stats_cache: HashMap::new(),
+			stats_requested: None,
+			async_stats: AsyncSingleJob::new(sender.clone()),
 			tags: Vec::new(),
+			selected_parent: 0,
+			diff_parent: DiffParent::default(),
+			queue: queue.clone(),
 			theme,
 			focused,
 			scroll_to_bottom_next_draw: Cell::new(false),
 			current_width: Cell::new(0),
 			scroll: VerticalScroll::new(),
+			interpret_ansi: false,
 			key_config,
 		}
 	}
@@ -71,10 +106,20 @@ impl DetailsComponent {
 	) {
 		self.tags.clear();
 
+		// refreshing the same commit keeps the chosen parent
+		let hash = id.map(|id| id.to_string());
+		if self.data.as_ref().map(|data| &data.hash) != hash.as_ref()
+		{
+			self.diff_parent = DiffParent::default();
+		}
+
 		self.data = id.and_then(|id| {
 			sync::get_commit_details(&self.repo.borrow(), id).ok()
 		});
 
+		self.fetch_stats(id);
+
+		self.selected_parent = 0;
 		self.scroll.reset();
 
 		if let Some(tags) = tags {
@@ -82,6 +127,141 @@ impl DetailsComponent {
 		}
 	}
 
+	/// shows the stats against the chosen parent if they were
+	/// computed already, computes them in the background otherwise
+	fn fetch_stats(&mut self, id: Option<CommitId>) {
+		let key = id.map(|id| (id, self.diff_parent));
+		self.stats =
+			key.and_then(|key| self.stats_cache.get(&key).copied());
+
+		if let Some((id, parent)) = key {
+			if self.stats.is_none() && self.stats_requested != key {
+				self.stats_requested = key;
+				self.async_stats.spawn(AsyncLineStatsJob::new(
+					self.repo.borrow().clone(),
+					id,
+					parent,
+				));
+			}
+		}
+	}
+
+	/// takes the stats computed in the background
+	pub fn update_stats(&mut self) {
+		if let Some(job) = self.async_stats.take_last() {
+			if let Some(Ok(stats)) = job.result() {
+				if self.stats_cache.len() >= STATS_CACHE_SIZE {
+					self.stats_cache.clear();
+				}
+				self.stats_cache.insert(job.params(), stats);
+			}
+
+			self.fetch_stats(self.commit_id());
+		}
+	}
+
+	///
+	pub fn any_work_pending(&self) -> bool {
+		self.async_stats.is_pending()
+	}
+
+	fn commit_id(&self) -> Option<CommitId> {
+		self.data.as_ref().and_then(|data| {
+			CommitId::from_str_unchecked(&data.hash).ok()
+		})
+	}
+
+	fn parents(&self) -> &[CommitId] {
+		self.data.as_ref().map_or(&[], |data| &data.parents)
+	}
+
+	fn select_next_parent(&mut self) -> bool {
+		let count = self.parents().len();
+		if count > 1 {
+			self.selected_parent = (self.selected_parent + 1) % count;
+			true
+		} else {
+			false
+		}
+	}
+
+	/// see `diff_parent`
+	pub const fn diff_parent(&self) -> DiffParent {
+		self.diff_parent
+	}
+
+	/// first, second, ... parent and then all of them combined,
+	/// only merge commits have a choice
+	fn select_next_diff_parent(&mut self) -> bool {
+		let count = self.parents().len();
+		if count < 2 {
+			return false;
+		}
+
+		self.diff_parent = match self.diff_parent {
+			DiffParent::Nth(idx) if idx + 1 < count => {
+				DiffParent::Nth(idx + 1)
+			}
+			DiffParent::Nth(_) => DiffParent::Combined,
+			DiffParent::Combined => DiffParent::Nth(0),
+		};
+		self.fetch_stats(self.commit_id());
+
+		true
+	}
+
+	fn inspect_selected_parent(&self) -> bool {
+		self.parents().get(self.selected_parent).map_or(
+			false,
+			|parent| {
+				self.queue.push(InternalEvent::OpenPopup(
+					StackablePopupOpen::InspectCommit(
+						InspectCommitOpen::new(*parent),
+					),
+				));
+				true
+			},
+		)
+	}
+
+	fn get_parents_line(&self, data: &CommitDetails) -> Spans {
+		let mut spans = vec![Span::styled(
+			Cow::from(strings::commit::details_parents()),
+			self.theme.text(false, false),
+		)];
+
+		if data.parents.is_empty() {
+			spans.push(Span::styled(
+				Cow::from(strings::commit::details_no_parents()),
+				self.theme.text(true, false),
+			));
+		}
+
+		for (idx, parent) in data.parents.iter().enumerate() {
+			if idx > 0 {
+				spans.push(Span::raw(" "));
+			}
+			spans.push(Span::styled(
+				Cow::from(parent.get_short_string()),
+				self.theme.commit_hash(
+					self.focused && idx == self.selected_parent,
+				),
+			));
+		}
+
+		if data.parents.len() > 1 {
+			spans.push(Span::raw(" "));
+			spans.push(Span::styled(
+				Cow::from(strings::commit::details_diff_parent(
+					self.diff_parent,
+				)),
+				self.theme.text(true, false),
+			));
+		}
+
+		Spans::from(spans)
+	}
+
 	fn wrap_commit_details(
 		message: &CommitMessage,
 		width: usize,
@@ -137,25 +317,68 @@ impl DetailsComponent {
 		let (wrapped_title, wrapped_message) =
 			Self::get_wrapped_lines(&self.data, width);
 
+		// textwrap skips escape sequences when measuring,
+		// so they only need handling once the lines are wrapped
+		let mut ansi_state = Style::default();
+
 		[&wrapped_title[..], &wrapped_message[..]]
 			.concat()
-			.iter()
+			.into_iter()
 			.enumerate()
-			.skip(self.scroll.get_top())
-			.take(height)
 			.map(|(i, line)| {
-				Spans::from(vec![Span::styled(
-					line.clone(),
-					self.get_theme_for_line(i < wrapped_title.len()),
-				)])
+				let style =
+					self.get_theme_for_line(i < wrapped_title.len());
+
+				if self.interpret_ansi {
+					Spans::from(ansi_spans(
+						&line,
+						style,
+						&mut ansi_state,
+					))
+				} else {
+					Spans::from(vec![Span::styled(
+						strip_ansi(line),
+						style,
+					)])
+				}
 			})
+			.skip(self.scroll.get_top())
+			.take(height)
 			.collect()
 	}
 
+	fn toggle_ansi(&mut self) -> bool {
+		self.interpret_ansi = !self.interpret_ansi;
+		true
+	}
+
 	#[allow(unstable_name_collisions, clippy::too_many_lines)]
 	fn get_text_info(&self) -> Vec<Spans> {
 		self.data.as_ref().map_or_else(Vec::new, |data| {
-			let mut res = vec![
+			let mut res = Vec::new();
+
+			if let Some(stats) = self.stats {
+				res.push(Spans::from(vec![
+					Span::styled(
+						Cow::from(
+							strings::commit::details_files_changed(
+								stats.files,
+							),
+						),
+						self.theme.text(true, false),
+					),
+					Span::styled(
+						Cow::from(format!("+{}", stats.added)),
+						self.theme.diff_stat(true),
+					),
+					Span::styled(
+						Cow::from(format!(" -{}", stats.removed)),
+						self.theme.diff_stat(false),
+					),
+				]));
+			}
+
+			res.extend(vec![
 				Spans::from(vec![
 					style_detail(&self.theme, &Detail::Author),
 					Span::styled(
@@ -176,7 +399,7 @@ impl DetailsComponent {
 						self.theme.text(true, false),
 					),
 				]),
-			];
+			]);
 
 			if let Some(ref committer) = data.committer {
 				res.extend(vec![
@@ -214,6 +437,8 @@ impl DetailsComponent {
 				),
 			]));
 
+			res.push(self.get_parents_line(data));
+
 			if !self.tags.is_empty() {
 				res.push(Spans::from(style_detail(
 					&self.theme,
@@ -262,7 +487,8 @@ impl DrawableComponent for DetailsComponent {
 		let chunks = Layout::default()
 			.direction(Direction::Vertical)
 			.constraints(
-				[Constraint::Length(8), Constraint::Min(10)].as_ref(),
+				[Constraint::Length(10), Constraint::Min(10)]
+					.as_ref(),
 			)
 			.split(rect);
 
@@ -354,6 +580,27 @@ impl Component for DetailsComponent {
 			.order(order::NAV),
 		);
 
+		out.push(CommandInfo::new(
+			strings::commands::inspect_parent(&self.key_config),
+			!self.parents().is_empty(),
+			self.focused || force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::diff_parent(&self.key_config),
+			self.parents().len() > 1,
+			self.focused || force_all,
+		));
+
+		out.push(CommandInfo::new(
+			strings::commands::toggle_commit_ansi(
+				&self.key_config,
+				self.interpret_ansi,
+			),
+			number_of_lines > 0,
+			self.focused || force_all,
+		));
+
 		CommandBlocking::PassingOn
 	}
 
@@ -372,6 +619,14 @@ impl Component for DetailsComponent {
 					|| e == self.key_config.keys.shift_down
 				{
 					self.move_scroll_top(ScrollType::End).into()
+				} else if e == self.key_config.keys.select_parent {
+					self.select_next_parent().into()
+				} else if e == self.key_config.keys.diff_parent {
+					self.select_next_diff_parent().into()
+				} else if e == self.key_config.keys.inspect_parent {
+					self.inspect_selected_parent().into()
+				} else if e == self.key_config.keys.toggle_ansi {
+					self.toggle_ansi().into()
 				} else {
 					EventState::NotConsumed
 				});
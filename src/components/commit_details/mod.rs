@@ -12,13 +12,14 @@ use crate::{
 };
 use anyhow::Result;
 use asyncgit::{
-	sync::{CommitTags, RepoPathRef},
+	sync::{CommitTags, DiffParent, RepoPathRef},
 	AsyncCommitFiles, AsyncGitNotification, CommitFilesParams,
 };
 use compare_details::CompareDetailsComponent;
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
 use details::DetailsComponent;
+use std::path::PathBuf;
 use tui::{
 	backend::Backend,
 	layout::{Constraint, Direction, Layout, Rect},
@@ -49,6 +50,8 @@ impl CommitDetailsComponent {
 		Self {
 			single_details: DetailsComponent::new(
 				repo.clone(),
+				queue,
+				sender,
 				theme.clone(),
 				key_config.clone(),
 				false,
@@ -96,32 +99,68 @@ impl CommitDetailsComponent {
 			self.compare_details.set_commits(None);
 		}
 
-		self.commit = params;
+		self.file_tree.set_commit(
+			params.filter(|p| p.other.is_none()).map(|p| p.id),
+		);
 
-		if let Some(id) = params {
+		if let Some(mut id) = params {
 			if let Some(other) = id.other {
 				self.compare_details
 					.set_commits(Some((id.id, other)));
 			} else {
 				self.single_details.set_commit(Some(id.id), tags);
+				id.parent = self.single_details.diff_parent();
 			}
 
-			if let Some((fetched_id, res)) =
-				self.git_commit_files.current()?
-			{
-				if fetched_id == id {
-					self.file_tree.update(res.as_slice())?;
-					self.file_tree.set_title(self.get_files_title());
+			self.commit = Some(id);
+			self.fetch_files(id)?;
+		} else {
+			self.commit = None;
+		}
 
-					return Ok(());
-				}
-			}
+		self.file_tree.set_title(self.get_files_title());
+
+		Ok(())
+	}
+
+	/// what the files of a single commit are listed against
+	pub fn diff_parent(&self) -> DiffParent {
+		self.commit
+			.filter(|p| p.other.is_none())
+			.map_or_else(DiffParent::default, |p| p.parent)
+	}
 
-			self.file_tree.clear()?;
-			self.git_commit_files.fetch(id)?;
+	/// shows the files of `params` if they were fetched already,
+	/// requests them otherwise
+	fn fetch_files(
+		&mut self,
+		params: CommitFilesParams,
+	) -> Result<()> {
+		if let Some((fetched, res)) =
+			self.git_commit_files.current()?
+		{
+			if fetched == params {
+				return self.file_tree.update(res.as_slice());
+			}
 		}
 
-		self.file_tree.set_title(self.get_files_title());
+		self.file_tree.clear()?;
+		self.git_commit_files.fetch(params)?;
+
+		Ok(())
+	}
+
+	/// refetches the files if another parent was picked
+	fn update_diff_parent(&mut self) -> Result<()> {
+		if let Some(mut params) = self.commit {
+			let parent = self.single_details.diff_parent();
+			if params.other.is_none() && params.parent != parent {
+				params.parent = parent;
+				self.commit = Some(params);
+				self.fetch_files(params)?;
+				self.file_tree.set_title(self.get_files_title());
+			}
+		}
 
 		Ok(())
 	}
@@ -129,6 +168,19 @@ impl CommitDetailsComponent {
 	///
 	pub fn any_work_pending(&self) -> bool {
 		self.git_commit_files.is_pending()
+			|| self.single_details.any_work_pending()
+	}
+
+	/// shows the diffstat once it is computed
+	pub fn update_line_stats(&mut self) {
+		self.single_details.update_stats();
+	}
+
+	/// jumps to the file picked in the file finder
+	pub fn file_finder_update(&mut self, file: &Option<PathBuf>) {
+		if self.file_tree.focused() {
+			self.file_tree.find_file(file);
+		}
 	}
 
 	///
@@ -223,6 +275,8 @@ impl Component for CommitDetailsComponent {
 				self.hide();
 			}
 
+			self.update_diff_parent()?;
+
 			return Ok(EventState::Consumed);
 		}
 
@@ -1,44 +1,150 @@
 use super::utils::logitems::{ItemBatch, LogEntry};
 use crate::{
 	components::{
-		utils::string_width_align, CommandBlocking, CommandInfo,
-		Component, DrawableComponent, EventState, ScrollType,
+		utils::{string_width_align, time_to_string},
+		CommandBlocking, CommandInfo, Component, DrawableComponent,
+		EventState, ScrollType,
 	},
 	keys::SharedKeyConfig,
+	log_columns::{LogColumn, SharedLogColumns},
+	spinner::SPINNER_CHARS,
 	strings::{self, symbol},
 	ui::style::{SharedTheme, Theme},
-	ui::{calc_scroll_top, draw_scrollbar},
+	ui::{calc_scroll_top, draw_scrollbar, draw_scrollbar_pending},
 };
 use anyhow::Result;
-use asyncgit::sync::{CommitId, Tags};
+use asyncgit::sync::{BranchCompare, CommitId, CommitInfo, Tags};
 use chrono::{DateTime, Local};
 use crossterm::event::Event;
 use itertools::Itertools;
 use std::{
-	borrow::Cow, cell::Cell, cmp, convert::TryFrom, time::Instant,
+	borrow::Cow,
+	cell::Cell,
+	cmp,
+	collections::{hash_map::Entry, HashMap},
+	convert::TryFrom,
+	sync::Arc,
+	time::Instant,
 };
 use tui::{
 	backend::Backend,
-	layout::{Alignment, Rect},
+	layout::{Alignment, Constraint, Direction, Layout, Rect},
 	text::{Span, Spans},
-	widgets::{Block, Borders, Paragraph},
+	widgets::{Block, Borders, Gauge, Paragraph},
 	Frame,
 };
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+#[cfg(feature = "ghemoji")]
+use super::utils::emoji::emojifi_string;
 
 const ELEMENTS_PER_LINE: usize = 9;
+/// columns of the bottom border taken by the load progress gauge
+const LOAD_PROGRESS_WIDTH: usize = 30;
+
+/// renders emoji shortcodes (`:sparkles:` -> ✨) if `emoji`,
+/// entries keep the raw message
+#[cfg(feature = "ghemoji")]
+fn display_msg(msg: &str, emoji: bool) -> Cow<'_, str> {
+	if emoji {
+		let mut msg = msg.to_string();
+		emojifi_string(&mut msg);
+		Cow::from(msg)
+	} else {
+		Cow::from(msg)
+	}
+}
+
+#[cfg(not(feature = "ghemoji"))]
+fn display_msg(msg: &str, _emoji: bool) -> Cow<'_, str> {
+	Cow::from(msg)
+}
+
+/// cuts `msg` to fit into `width` columns,
+/// ending in an ellipsis if anything was cut off
+fn truncate_msg(msg: &str, width: usize) -> String {
+	if msg.width() <= width {
+		return msg.to_string();
+	}
+
+	width.checked_sub(1).map_or_else(String::new, |width| {
+		format!(
+			"{}{}",
+			split_at_width(msg, width).0,
+			symbol::ELLIPSIS
+		)
+	})
+}
+
+/// splits `s` after the chars fitting into `width` columns
+fn split_at_width(s: &str, width: usize) -> (&str, &str) {
+	let mut used = 0;
+	for (idx, c) in s.char_indices() {
+		used += c.width().unwrap_or(0);
+		if used > width {
+			return s.split_at(idx);
+		}
+	}
+
+	(s, "")
+}
+
+/// settings shared by all entries drawn at once
+#[derive(Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
+struct EntryRenderOptions<'a> {
+	theme: &'a Theme,
+	width: usize,
+	now: DateTime<Local>,
+	head: Option<CommitId>,
+	show_author_rank: bool,
+	emoji: bool,
+	wrap: bool,
+	full_hash: bool,
+	message_max_length: Option<usize>,
+	columns: &'a [LogColumn],
+	signatures: &'a HashMap<CommitId, bool>,
+}
 
 ///
+#[allow(clippy::struct_excessive_bools)]
 pub struct CommitList {
 	title: Box<str>,
 	selection: usize,
 	branch: Option<String>,
+	branch_compare: Option<BranchCompare>,
 	count_total: usize,
 	items: ItemBatch,
 	marked: Vec<CommitId>,
 	scroll_state: (Instant, f32),
-	tags: Option<Tags>,
+	tags: Option<Arc<Tags>>,
 	current_size: Cell<(u16, u16)>,
+	/// index of the first commit shown, not relative to `items`
+	/// so it stays put when another window of commits is loaded
 	scroll_top: Cell<usize>,
+	focused: bool,
+	show_tags: bool,
+	show_author_rank: bool,
+	/// render emoji shortcodes in commit messages
+	show_emoji: bool,
+	/// which columns are shown in what order
+	columns: SharedLogColumns,
+	/// cached signature state per commit
+	signatures: HashMap<CommitId, bool>,
+	/// spinner frame shown in the title while loading
+	pending_frame: Option<usize>,
+	/// shown in the bottom border until the selection moves
+	status: Option<String>,
+	/// shown in a separate section above the scrolling list
+	pinned: Vec<LogEntry>,
+	/// highlighted to make it easy to find
+	head: Option<CommitId>,
+	/// number of commits the running load is expected to end at,
+	/// see `draw_load_progress`
+	load_bound: Option<usize>,
+	/// configured cap for the message width,
+	/// on top of the width of the list
+	message_max_length: Option<usize>,
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 }
@@ -49,31 +155,188 @@ impl CommitList {
 		title: &str,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		columns: SharedLogColumns,
 	) -> Self {
 		Self {
 			items: ItemBatch::default(),
 			marked: Vec::with_capacity(2),
 			selection: 0,
 			branch: None,
+			branch_compare: None,
 			count_total: 0,
 			scroll_state: (Instant::now(), 0_f32),
 			tags: None,
 			current_size: Cell::new((0, 0)),
 			scroll_top: Cell::new(0),
+			focused: true,
+			show_tags: true,
+			show_author_rank: false,
+			show_emoji: true,
+			columns,
+			signatures: HashMap::new(),
+			pending_frame: None,
+			status: None,
+			pinned: Vec::new(),
+			head: None,
+			load_bound: None,
+			message_max_length: None,
 			theme,
 			key_config,
 			title: title.into(),
 		}
 	}
 
+	/// selects the closest newer loaded commit that has the selected
+	/// commit as a parent, returns `false` if there is none
+	pub fn select_child(&mut self) -> bool {
+		if let Some(idx) = self.items.child_index(self.selection) {
+			self.selection = idx;
+			true
+		} else {
+			false
+		}
+	}
+
+	/// show or hide tag names behind the commit msg
+	pub fn toggle_tags(&mut self) {
+		self.show_tags = !self.show_tags;
+	}
+
+	/// annotate authors with their nth commit in the loaded list
+	pub fn toggle_author_rank(&mut self) {
+		self.show_author_rank = !self.show_author_rank;
+	}
+
+	/// render shortcodes like `:sparkles:` as emoji or show them raw
+	pub fn toggle_emoji(&mut self) {
+		self.show_emoji = !self.show_emoji;
+	}
+
+	/// see `LogColumns::wrap_message`
+	pub fn wrap_message(&self) -> bool {
+		self.columns.borrow().wrap_message()
+	}
+
+	/// wraps long messages to a second line or cuts them,
+	/// the preference is saved right away
+	pub fn toggle_wrap_message(&self) -> Result<()> {
+		self.columns.borrow_mut().toggle_wrap_message();
+		self.columns.borrow().save()
+	}
+
+	/// only hash and message (see `LogColumns::oneline`),
+	/// the preference is saved right away
+	pub fn toggle_oneline(&self) -> Result<()> {
+		self.columns.borrow_mut().toggle_oneline();
+		self.columns.borrow().save()
+	}
+
+	/// full or abbreviated hashes (see `LogColumns::full_hash`),
+	/// the preference is saved right away
+	pub fn toggle_full_hash(&self) -> Result<()> {
+		self.columns.borrow_mut().toggle_full_hash();
+		self.columns.borrow().save()
+	}
+
+	/// show colored author initials at the start of each row,
+	/// the preference is saved right away
+	pub fn toggle_initials(&self) -> Result<()> {
+		self.columns.borrow_mut().toggle(LogColumn::Initials);
+		self.columns.borrow().save()
+	}
+
+	/// show how long after authoring each commit was committed,
+	/// the preference is saved right away
+	pub fn toggle_commit_delay(&self) -> Result<()> {
+		self.columns.borrow_mut().toggle(LogColumn::CommitDelay);
+		self.columns.borrow().save()
+	}
+
+	/// show or hide the signature state of commits,
+	/// the preference is saved right away
+	pub fn toggle_signatures(&mut self) -> Result<()> {
+		self.columns.borrow_mut().toggle(LogColumn::Signature);
+		if !self.signatures_shown() {
+			self.signatures.clear();
+		}
+		self.columns.borrow().save()
+	}
+
+	/// see `toggle_signatures`
+	pub fn signatures_shown(&self) -> bool {
+		self.columns.borrow().is_visible(LogColumn::Signature)
+	}
+
+	/// computes the signature state of loaded commits not cached yet,
+	/// commits `signed` returns `None` for stay unknown
+	pub fn cache_signatures<F>(&mut self, signed: F)
+	where
+		F: Fn(CommitId) -> Option<bool>,
+	{
+		if self.signatures_shown() {
+			for e in self.items.iter() {
+				if let Entry::Vacant(entry) =
+					self.signatures.entry(e.id)
+				{
+					if let Some(state) = signed(e.id) {
+						entry.insert(state);
+					}
+				}
+			}
+		}
+	}
+
+	/// advances the title spinner while `pending`, removes it otherwise
+	pub fn set_pending(&mut self, pending: bool) {
+		self.pending_frame =
+			if pending {
+				Some(self.pending_frame.map_or(0, |frame| {
+					(frame + 1) % SPINNER_CHARS.len()
+				}))
+			} else {
+				None
+			};
+	}
+
+	/// see `load_bound`
+	pub fn set_load_bound(&mut self, bound: Option<usize>) {
+		self.load_bound = bound;
+	}
+
+	///
+	pub fn set_title(&mut self, title: Box<str>) {
+		self.title = title;
+	}
+
 	///
 	pub fn items(&mut self) -> &mut ItemBatch {
 		&mut self.items
 	}
 
-	///
-	pub fn set_branch(&mut self, name: Option<String>) {
+	/// sets the branch name shown in the title
+	/// and its ahead/behind state relative to its upstream if any
+	pub fn set_branch(
+		&mut self,
+		name: Option<String>,
+		compare: Option<BranchCompare>,
+	) {
 		self.branch = name;
+		self.branch_compare = compare;
+	}
+
+	/// see `set_branch`
+	pub fn branch(&self) -> Option<&str> {
+		self.branch.as_deref()
+	}
+
+	/// see `head`
+	pub fn set_head(&mut self, head: Option<CommitId>) {
+		self.head = head;
+	}
+
+	/// see `message_max_length`
+	pub fn set_message_max_length(&mut self, max: Option<usize>) {
+		self.message_max_length = max;
 	}
 
 	///
@@ -100,25 +363,61 @@ impl CommitList {
 	}
 
 	///
-	pub const fn tags(&self) -> Option<&Tags> {
-		self.tags.as_ref()
+	pub fn tags(&self) -> Option<&Tags> {
+		self.tags.as_deref()
 	}
 
 	///
 	pub fn clear(&mut self) {
 		self.items.clear();
+		self.status = None;
+	}
+
+	/// see `status`
+	pub fn set_status(&mut self, status: Option<String>) {
+		self.status = status;
+	}
+
+	/// like `clear` but also drops cached per commit state
+	pub fn clear_cached(&mut self) {
+		self.clear();
+		self.signatures.clear();
+	}
+
+	/// see `pinned`
+	pub fn set_pinned(&mut self, pinned: Vec<CommitInfo>) {
+		self.pinned =
+			pinned.into_iter().map(LogEntry::from).collect();
 	}
 
 	///
-	pub fn set_tags(&mut self, tags: Tags) {
+	pub fn set_tags(&mut self, tags: Arc<Tags>) {
 		self.tags = Some(tags);
 	}
 
+	/// the closest older (or newer) of the `tagged` positions than
+	/// the selection, starting over from the other end if `wrap`
+	pub fn tagged_position(
+		&self,
+		tagged: &[usize],
+		older: bool,
+		wrap: bool,
+	) -> Option<usize> {
+		let after =
+			tagged.iter().copied().filter(|p| *p > self.selection);
+		let before =
+			tagged.iter().copied().filter(|p| *p < self.selection);
+
+		if older {
+			after.min().or_else(|| before.min().filter(|_| wrap))
+		} else {
+			before.max().or_else(|| after.max().filter(|_| wrap))
+		}
+	}
+
 	///
 	pub fn selected_entry(&self) -> Option<&LogEntry> {
-		self.items.iter().nth(
-			self.selection.saturating_sub(self.items.index_offset()),
-		)
+		self.items.get(self.selection)
 	}
 
 	///
@@ -143,11 +442,22 @@ impl CommitList {
 		self.marked.clear();
 	}
 
-	pub fn copy_entry_hash(&self) -> Result<()> {
-		if let Some(e) = self.items.iter().nth(
-			self.selection.saturating_sub(self.items.index_offset()),
-		) {
-			crate::clipboard::copy_string(&e.hash_short)?;
+	/// copies the short hashes of the marked commits or the selected
+	/// one, `full` copies the whole 40 character hashes instead
+	pub fn copy_entry_hash(&self, full: bool) -> Result<()> {
+		let hash = |id: &CommitId| {
+			if full {
+				id.to_string()
+			} else {
+				id.get_short_string()
+			}
+		};
+
+		if !self.marked.is_empty() {
+			let hashes = self.marked.iter().map(hash).join("\n");
+			crate::clipboard::copy_string(&hashes)?;
+		} else if let Some(e) = self.selected_entry() {
+			crate::clipboard::copy_string(&hash(&e.id))?;
 		}
 		Ok(())
 	}
@@ -184,6 +494,7 @@ impl CommitList {
 		let needs_update = new_selection != self.selection;
 
 		self.selection = new_selection;
+		self.status = None;
 
 		Ok(needs_update)
 	}
@@ -232,22 +543,96 @@ impl CommitList {
 		}
 	}
 
+	/// the cell of `column` for entry `e`
+	fn column_span<'a>(
+		column: LogColumn,
+		e: &'a LogEntry,
+		opts: &EntryRenderOptions,
+	) -> Span<'a> {
+		let EntryRenderOptions { theme, width, .. } = *opts;
+
+		match column {
+			LogColumn::Initials => Span::styled(
+				Cow::from(e.author_initials()),
+				theme.author_avatar(&e.author, false),
+			),
+			LogColumn::Signature => {
+				let signed = opts.signatures.get(&e.id).copied();
+				Span::styled(
+					Cow::from(match signed {
+						Some(true) => symbol::CHECKMARK,
+						Some(false) => symbol::CROSSMARK,
+						None => symbol::QUESTION_MARK,
+					}),
+					theme.commit_signature(signed, false),
+				)
+			}
+			LogColumn::Hash => Span::styled(
+				if opts.full_hash {
+					Cow::from(e.id.to_string())
+				} else {
+					Cow::from(&*e.hash_short)
+				},
+				theme.commit_hash(false),
+			),
+			LogColumn::Time => Span::styled(
+				Cow::from(e.time_to_string(opts.now)),
+				theme.commit_time(false),
+			),
+			LogColumn::CommitDelay => Span::styled(
+				Cow::from(e.commit_delay_to_string()),
+				theme.commit_time(false),
+			),
+			LogColumn::Author => {
+				let author_width =
+					(width.saturating_sub(19) / 3).max(3).min(20);
+				let author = if opts.show_author_rank {
+					string_width_align(
+						&format!(
+							"#{} by {}",
+							e.author_rank, e.author
+						),
+						author_width,
+					)
+				} else {
+					string_width_align(&e.author, author_width)
+				};
+
+				Span::styled(
+					Cow::from(author),
+					theme.commit_author(false),
+				)
+			}
+		}
+	}
+
 	fn get_entry_to_add<'a>(
 		e: &'a LogEntry,
 		selected: bool,
 		tags: Option<String>,
-		theme: &Theme,
-		width: usize,
-		now: DateTime<Local>,
 		marked: Option<bool>,
-	) -> Spans<'a> {
+		opts: &EntryRenderOptions,
+	) -> Vec<Spans<'a>> {
+		let EntryRenderOptions {
+			theme,
+			width,
+			head,
+			emoji,
+			wrap,
+			message_max_length,
+			columns,
+			..
+		} = *opts;
+
 		let mut txt: Vec<Span> = Vec::with_capacity(
-			ELEMENTS_PER_LINE + if marked.is_some() { 2 } else { 0 },
+			ELEMENTS_PER_LINE
+				+ if marked.is_some() { 2 } else { 0 }
+				+ columns.len() * 2,
 		);
 
 		let splitter_txt = Cow::from(symbol::EMPTY_SPACE);
 		let splitter =
-			Span::styled(splitter_txt, theme.text(true, selected));
+			Span::styled(splitter_txt, theme.text(true, false));
 
 		// marker
 		if let Some(marked) = marked {
@@ -257,98 +642,284 @@ impl CommitList {
 				} else {
 					symbol::EMPTY_SPACE
 				}),
-				theme.log_marker(selected),
+				theme.log_marker(false),
 			));
 			txt.push(splitter.clone());
 		}
 
-		// commit hash
-		txt.push(Span::styled(
-			Cow::from(&*e.hash_short),
-			theme.commit_hash(selected),
-		));
-
-		txt.push(splitter.clone());
+		for column in columns {
+			txt.push(Self::column_span(*column, e, opts));
+			txt.push(splitter.clone());
+		}
 
-		// commit timestamp
-		txt.push(Span::styled(
-			Cow::from(e.time_to_string(now)),
-			theme.commit_time(selected),
-		));
+		let tags = tags.map(|tags| format!("({})", tags));
+		let prefix_width: usize = txt.iter().map(Span::width).sum();
 
-		txt.push(splitter.clone());
+		// truncate the msg rather than dropping the tags behind it
+		let msg_width =
+			width.saturating_sub(prefix_width).saturating_sub(
+				tags.as_deref().map_or(0, |tags| {
+					tags.width() + splitter.width()
+				}),
+			);
+		let msg = display_msg(&e.msg, emoji);
+		let msg = match message_max_length {
+			Some(max) if msg.width() > max => {
+				Cow::from(truncate_msg(&msg, max))
+			}
+			_ => msg,
+		};
+		let msg_style = if head == Some(e.id) {
+			theme.commit_head(false)
+		} else {
+			theme.text(true, false)
+		};
 
-		let author_width =
-			(width.saturating_sub(19) / 3).max(3).min(20);
-		let author = string_width_align(&e.author, author_width);
+		let mut lines = vec![txt];
+
+		if wrap && msg.width() > msg_width {
+			// continue the msg below itself, the tags go behind the rest
+			let (head, tail) = split_at_width(
+				&msg,
+				width.saturating_sub(prefix_width),
+			);
+			// prefer breaking between words
+			let (head, tail) = match head.rfind(' ') {
+				Some(idx) if idx > 0 && !tail.starts_with(' ') => {
+					msg.split_at(idx)
+				}
+				_ => (head, tail),
+			};
+			let tail = tail.trim_start();
+			let tail = truncate_msg(tail, msg_width);
 
-		// commit author
-		txt.push(Span::styled::<String>(
-			author,
-			theme.commit_author(selected),
-		));
+			lines[0].push(Span::styled(
+				Cow::from(head.to_string()),
+				msg_style,
+			));
+			lines.push(vec![
+				Span::styled(
+					Cow::from(" ".repeat(prefix_width)),
+					theme.text(true, false),
+				),
+				Span::styled(Cow::from(tail), msg_style),
+			]);
+		} else {
+			let msg = if msg.width() > msg_width {
+				Cow::from(truncate_msg(&msg, msg_width))
+			} else {
+				msg
+			};
 
-		txt.push(splitter.clone());
+			// commit msg
+			lines[0].push(Span::styled(msg, msg_style));
+		}
 
 		// commit tags
-		txt.push(Span::styled(
-			Cow::from(tags.map_or_else(
-				|| String::from(""),
-				|tags| format!(" {}", tags),
-			)),
-			theme.tags(selected),
-		));
-
-		txt.push(splitter);
+		if let (Some(tags), Some(last)) = (tags, lines.last_mut()) {
+			last.push(splitter);
+			last.push(Span::styled(
+				Cow::from(tags),
+				theme.tags(false),
+			));
+		}
 
-		// commit msg
-		txt.push(Span::styled(
-			Cow::from(&*e.msg),
-			theme.text(true, selected),
-		));
+		if selected {
+			for span in lines.iter_mut().flatten() {
+				span.style = theme.commit_selected(span.style);
+			}
+		}
 
-		Spans::from(txt)
+		lines.into_iter().map(Spans::from).collect()
 	}
 
 	fn get_text(&self, height: usize, width: usize) -> Vec<Spans> {
 		let selection = self.relative_selection();
+		let top = self
+			.scroll_top
+			.get()
+			.saturating_sub(self.items.index_offset());
 
 		let mut txt: Vec<Spans> = Vec::with_capacity(height);
 
-		let now = Local::now();
+		let any_marked = !self.marked.is_empty();
+
+		let columns = self.columns.borrow().visible();
+		let opts = self.render_options(width, &columns);
+
+		for (idx, e) in
+			self.items.iter().skip(top).take(height).enumerate()
+		{
+			txt.extend(self.entry_lines(
+				e,
+				idx + top == selection,
+				any_marked,
+				&opts,
+			));
+
+			if txt.len() >= height {
+				break;
+			}
+		}
+
+		txt.truncate(height);
+		txt
+	}
+
+	fn entry_lines<'a>(
+		&self,
+		e: &'a LogEntry,
+		selected: bool,
+		any_marked: bool,
+		opts: &EntryRenderOptions,
+	) -> Vec<Spans<'a>> {
+		let marked = if any_marked {
+			self.is_marked(&e.id)
+		} else {
+			None
+		};
+
+		Self::get_entry_to_add(
+			e,
+			selected,
+			self.entry_tags(e),
+			marked,
+			opts,
+		)
+	}
+
+	/// keeps the selection inside the `height` lines shown,
+	/// wrapped entries can take more than one of them
+	fn next_scroll_top(&self, height: usize, width: usize) -> usize {
+		let selection = self.selection;
+		let current_top = self.scroll_top.get();
+
+		if !self.wrap_message()
+			|| !self.items.contains_index(selection)
+		{
+			return calc_scroll_top(current_top, height, selection);
+		}
+		if height == 0 {
+			return 0;
+		}
+		if current_top > selection {
+			return selection;
+		}
 
 		let any_marked = !self.marked.is_empty();
+		let columns = self.columns.borrow().visible();
+		let opts = self.render_options(width, &columns);
 
+		// first entry that still shows the whole selection below it
+		let mut lines = 0;
+		let mut min_top = selection;
 		for (idx, e) in self
 			.items
 			.iter()
-			.skip(self.scroll_top.get())
-			.take(height)
 			.enumerate()
+			.take(self.relative_selection() + 1)
+			.rev()
 		{
-			let tags =
-				self.tags.as_ref().and_then(|t| t.get(&e.id)).map(
-					|tags| tags.iter().map(|t| &t.name).join(" "),
-				);
+			lines +=
+				self.entry_lines(e, false, any_marked, &opts).len();
+			if lines > height {
+				break;
+			}
+			min_top = idx + self.items.index_offset();
+		}
 
-			let marked = if any_marked {
-				self.is_marked(&e.id)
-			} else {
-				None
-			};
+		current_top.max(min_top)
+	}
 
-			txt.push(Self::get_entry_to_add(
-				e,
-				idx + self.scroll_top.get() == selection,
-				tags,
-				&self.theme,
-				width,
-				now,
-				marked,
-			));
+	fn render_options<'a>(
+		&'a self,
+		width: usize,
+		columns: &'a [LogColumn],
+	) -> EntryRenderOptions<'a> {
+		EntryRenderOptions {
+			theme: &self.theme,
+			width,
+			now: Local::now(),
+			head: self.head,
+			show_author_rank: self.show_author_rank,
+			emoji: self.show_emoji,
+			wrap: self.wrap_message(),
+			full_hash: self.columns.borrow().full_hash(),
+			message_max_length: self.message_max_length,
+			columns,
+			signatures: &self.signatures,
 		}
+	}
 
-		txt
+	fn entry_tags(&self, e: &LogEntry) -> Option<String> {
+		self.tags
+			.as_ref()
+			.filter(|_| self.show_tags)
+			.and_then(|t| t.get(&e.id))
+			.map(|tags| tags.iter().map(|t| &t.name).join(", "))
+	}
+
+	/// draws the pinned commits (if any) at the top of `area`
+	/// and returns the area left for the list
+	fn draw_pinned<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Rect {
+		let height = u16::try_from(self.pinned.len())
+			.unwrap_or(u16::MAX)
+			.saturating_add(2)
+			.min(area.height / 3);
+
+		if self.pinned.is_empty() || height < 3 {
+			return area;
+		}
+
+		let chunks = Layout::default()
+			.direction(Direction::Vertical)
+			.constraints(
+				[Constraint::Length(height), Constraint::Min(0)]
+					.as_ref(),
+			)
+			.split(area);
+
+		let columns = self.columns.borrow().visible();
+		let width = usize::from(chunks[0].width.saturating_sub(2));
+		let opts = EntryRenderOptions {
+			wrap: false,
+			..self.render_options(width, &columns)
+		};
+
+		let txt = self
+			.pinned
+			.iter()
+			.flat_map(|e| {
+				Self::get_entry_to_add(
+					e,
+					false,
+					self.entry_tags(e),
+					None,
+					&opts,
+				)
+			})
+			.collect::<Vec<_>>();
+
+		f.render_widget(
+			Paragraph::new(txt)
+				.block(
+					Block::default()
+						.borders(Borders::ALL)
+						.title(Span::styled(
+							strings::log_pinned_title(),
+							self.theme.title(false),
+						))
+						.border_style(self.theme.block(false)),
+				)
+				.alignment(Alignment::Left),
+			chunks[0],
+		);
+
+		chunks[1]
 	}
 
 	#[allow(clippy::missing_const_for_fn)]
@@ -359,6 +930,162 @@ impl CommitList {
 	pub fn select_entry(&mut self, position: usize) {
 		self.selection = position;
 	}
+
+	/// the list only shows relative times, so show the absolute
+	/// time of the selected commit in the bottom border
+	fn selected_time(&self) -> Option<String> {
+		self.selected_entry().map(|entry| {
+			format!(
+				" {} ",
+				time_to_string(entry.time.timestamp(), false)
+			)
+		})
+	}
+
+	/// the bottom border between its corners split into `left` and
+	/// `right` columns at its ends and the rest in the center,
+	/// `left` wins if there is not enough room for both
+	fn bottom_border_regions(
+		area: Rect,
+		left: usize,
+		right: usize,
+	) -> Option<[Rect; 3]> {
+		if area.height < 3 || area.width < 3 {
+			return None;
+		}
+
+		let width = area.width - 2;
+		let left = u16::try_from(left).unwrap_or(u16::MAX).min(width);
+		let right = u16::try_from(right)
+			.unwrap_or(u16::MAX)
+			.min(width - left);
+		let y = area.y + area.height - 1;
+
+		Some([
+			Rect::new(area.x + 1, y, left, 1),
+			Rect::new(area.x + 1 + left, y, width - left - right, 1),
+			Rect::new(area.x + 1 + width - right, y, right, 1),
+		])
+	}
+
+	/// draws the status if set or the load progress while loading,
+	/// the more hint and the selected time into the bottom border
+	fn draw_bottom_border<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) {
+		let status = self
+			.status
+			.as_ref()
+			.map(|status| format!(" {} ", status));
+		let time = self.selected_time();
+
+		let left = match &status {
+			Some(status) => status.width(),
+			None if self.pending_frame.is_some() => {
+				LOAD_PROGRESS_WIDTH
+			}
+			None => 0,
+		};
+
+		if let Some([left, center, right]) =
+			Self::bottom_border_regions(
+				area,
+				left,
+				time.as_deref().map_or(0, UnicodeWidthStr::width),
+			) {
+			if let Some(status) = status {
+				self.draw_status(f, left, status);
+			} else {
+				self.draw_load_progress(f, left);
+			}
+
+			self.draw_more_below(f, center);
+
+			if let Some(time) = time {
+				f.render_widget(
+					Paragraph::new(Span::styled(
+						time,
+						self.theme.text(false, false),
+					)),
+					right,
+				);
+			}
+		}
+	}
+
+	/// gauge of the loaded commits while loading,
+	/// only the count is shown without a `load_bound`
+	fn draw_load_progress<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) {
+		if self.pending_frame.is_none() || rect.width == 0 {
+			return;
+		}
+
+		#[allow(clippy::cast_precision_loss)]
+		let ratio = self.load_bound.filter(|bound| *bound > 0).map_or(
+			0_f64,
+			|bound| {
+				(self.count_total as f64 / bound as f64).min(1_f64)
+			},
+		);
+
+		f.render_widget(
+			Gauge::default()
+				.label(strings::log_load_progress(
+					self.count_total,
+					self.load_bound,
+				))
+				.gauge_style(self.theme.push_gauge())
+				.ratio(ratio),
+			rect,
+		);
+	}
+
+	/// hints that the list goes on below the last loaded commit
+	/// if it is selected, until the real end of the log is reached
+	fn draw_more_below<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) {
+		if rect.width == 0
+			|| !self.items.more_below(
+				self.selection,
+				self.count_total,
+				self.pending_frame.is_some(),
+			) {
+			return;
+		}
+
+		f.render_widget(
+			Paragraph::new(Span::styled(
+				format!(" {} ", strings::log_more_below()),
+				self.theme.text(false, false),
+			))
+			.alignment(Alignment::Center),
+			rect,
+		);
+	}
+
+	fn draw_status<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+		status: String,
+	) {
+		f.render_widget(
+			Paragraph::new(Span::styled(
+				status,
+				self.theme.text(true, false),
+			)),
+			rect,
+		);
+	}
 }
 
 impl DrawableComponent for CommitList {
@@ -367,6 +1094,8 @@ impl DrawableComponent for CommitList {
 		f: &mut Frame<B>,
 		area: Rect,
 	) -> Result<()> {
+		let area = self.draw_pinned(f, area);
+
 		let current_size = (
 			area.width.saturating_sub(2),
 			area.height.saturating_sub(2),
@@ -374,20 +1103,34 @@ impl DrawableComponent for CommitList {
 		self.current_size.set(current_size);
 
 		let height_in_lines = self.current_size.get().1 as usize;
-		let selection = self.relative_selection();
 
-		self.scroll_top.set(calc_scroll_top(
-			self.scroll_top.get(),
+		self.scroll_top.set(self.next_scroll_top(
 			height_in_lines,
-			selection,
+			current_size.0 as usize,
 		));
 
-		let branch_post_fix =
-			self.branch.as_ref().map(|b| format!("- {{{}}}", b));
+		let branch_post_fix = self.branch.as_ref().map(|b| {
+			let ahead_behind = self
+				.branch_compare
+				.as_ref()
+				.map_or_else(String::new, |state| {
+					format!(
+						" \u{2191}{} \u{2193}{}",
+						state.ahead, state.behind,
+					)
+				});
+
+			format!("- {{{}{}}}", b, ahead_behind)
+		});
+
+		let spinner = self
+			.pending_frame
+			.map(|frame| format!(" {}", SPINNER_CHARS[frame]));
 
 		let title = format!(
-			"{} {}/{} {}",
+			"{}{} {}/{} {}",
 			self.title,
+			spinner.as_deref().unwrap_or(""),
 			self.count_total.saturating_sub(self.selection),
 			self.count_total,
 			branch_post_fix.as_deref().unwrap_or(""),
@@ -405,21 +1148,28 @@ impl DrawableComponent for CommitList {
 					.borders(Borders::ALL)
 					.title(Span::styled(
 						title.as_str(),
-						self.theme.title(true),
+						self.theme.title(self.focused),
 					))
-					.border_style(self.theme.block(true)),
+					.border_style(self.theme.block(self.focused)),
 			)
 			.alignment(Alignment::Left),
 			area,
 		);
 
-		draw_scrollbar(
-			f,
-			area,
-			&self.theme,
-			self.count_total,
-			self.selection,
-		);
+		// the total still grows while loading
+		if self.pending_frame.is_some() {
+			draw_scrollbar_pending(f, area);
+		} else {
+			draw_scrollbar(
+				f,
+				area,
+				&self.theme,
+				self.count_total,
+				self.selection,
+			);
+		}
+
+		self.draw_bottom_border(f, area);
 
 		Ok(())
 	}
@@ -477,11 +1227,26 @@ impl Component for CommitList {
 		));
 		CommandBlocking::PassingOn
 	}
+
+	fn focused(&self) -> bool {
+		self.focused
+	}
+
+	fn focus(&mut self, focus: bool) {
+		self.focused = focus;
+	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::{
+		components::utils::logitems::tests::{
+			commit_id, commit_info,
+		},
+		log_columns::LogColumns,
+	};
+	use tui::style::Style;
 
 	#[test]
 	fn test_string_width_align() {
@@ -504,4 +1269,461 @@ mod tests {
 			"Jon Grythe Stødle  "
 		);
 	}
+
+	fn test_entry(msg: &str) -> LogEntry {
+		LogEntry::from(CommitInfo {
+			message: msg.into(),
+			author: "author".into(),
+			id: CommitId::from_str_unchecked(
+				"0123456789abcdef0123456789abcdef01234567",
+			)
+			.unwrap(),
+			..commit_info(0)
+		})
+	}
+
+	/// renders `entry` 80 columns wide with the default columns,
+	/// changed by `opts`
+	fn render_entry<'a>(
+		entry: &'a LogEntry,
+		selected: bool,
+		tags: Option<&str>,
+		marked: Option<bool>,
+		opts: impl FnOnce(&mut EntryRenderOptions),
+	) -> Vec<Spans<'a>> {
+		let theme = Theme::default();
+		let columns = LogColumns::default().visible();
+		let signatures = HashMap::new();
+		let mut options = EntryRenderOptions {
+			theme: &theme,
+			width: 80,
+			now: entry.time,
+			head: None,
+			show_author_rank: false,
+			emoji: true,
+			wrap: false,
+			full_hash: false,
+			message_max_length: None,
+			columns: &columns,
+			signatures: &signatures,
+		};
+		opts(&mut options);
+
+		CommitList::get_entry_to_add(
+			entry,
+			selected,
+			tags.map(String::from),
+			marked,
+			&options,
+		)
+	}
+
+	fn text(lines: &[Spans]) -> Vec<String> {
+		lines
+			.iter()
+			.map(|line| {
+				line.0
+					.iter()
+					.map(|span| span.content.as_ref())
+					.collect()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn test_tags_behind_truncated_msg() {
+		let entry = test_entry("a rather long commit message");
+
+		let width = 50;
+		let text = text(&render_entry(
+			&entry,
+			false,
+			Some("v1.2.0"),
+			None,
+			|o| o.width = width,
+		))
+		.remove(0);
+
+		assert!(text.ends_with(" (v1.2.0)"));
+		assert!(text.contains(symbol::ELLIPSIS));
+		assert_eq!(text.width(), width);
+	}
+
+	#[test]
+	fn test_full_hash_shrinks_msg() {
+		let entry = test_entry("a rather long commit message");
+
+		let render = |full_hash: bool| -> String {
+			text(&render_entry(&entry, false, None, None, |o| {
+				o.width = 60;
+				o.full_hash = full_hash;
+				o.columns = &[LogColumn::Hash];
+			}))
+			.remove(0)
+		};
+
+		assert_eq!(
+			render(false),
+			"0123456 a rather long commit message"
+		);
+
+		let full = render(true);
+		assert!(full.starts_with(
+			"0123456789abcdef0123456789abcdef01234567 a rather"
+		));
+		assert!(full.ends_with(symbol::ELLIPSIS));
+		assert_eq!(full.width(), 60);
+	}
+
+	#[test]
+	fn test_message_max_length() {
+		let entry = test_entry("a rather long commit message");
+
+		let render = |max: Option<usize>| -> String {
+			text(&render_entry(&entry, false, None, None, |o| {
+				o.message_max_length = max;
+				o.columns = &[];
+			}))
+			.remove(0)
+		};
+
+		assert_eq!(render(None), "a rather long commit message");
+		assert_eq!(render(Some(8)), "a rathe\u{2026}");
+		assert_eq!(render(Some(100)), "a rather long commit message");
+	}
+
+	#[test]
+	fn test_wrapped_msg() {
+		let entry = test_entry(
+			"a rather long commit message that has to be wrapped",
+		);
+
+		let width = 40;
+		let text = text(&render_entry(
+			&entry,
+			false,
+			Some("v1.2.0"),
+			None,
+			|o| {
+				o.width = width;
+				o.wrap = true;
+				o.columns = &[LogColumn::Hash];
+			},
+		));
+
+		assert_eq!(text.len(), 2);
+		assert_eq!(text[0], "0123456 a rather long commit message");
+		assert_eq!(
+			text[1],
+			"        that has to be wrapped (v1.2.0)"
+		);
+		assert!(text.iter().all(|line| line.width() <= width));
+	}
+
+	#[test]
+	fn test_split_at_width() {
+		assert_eq!(split_at_width("abc", 5), ("abc", ""));
+		assert_eq!(split_at_width("abcdef", 3), ("abc", "def"));
+		assert_eq!(split_at_width("日本語", 3), ("日", "本語"));
+	}
+
+	#[test]
+	fn test_head_msg_style() {
+		let entry = test_entry("msg");
+		let theme = Theme::default();
+
+		let msg_style = |head: Option<CommitId>| {
+			let lines =
+				render_entry(&entry, false, None, None, |o| {
+					o.head = head;
+				});
+			lines[0].0.last().unwrap().style
+		};
+
+		assert_eq!(
+			msg_style(Some(entry.id)),
+			theme.commit_head(false)
+		);
+		assert_eq!(msg_style(None), theme.text(true, false));
+	}
+
+	#[test]
+	fn test_selected_style() {
+		let entry = test_entry("msg");
+		let theme = Theme::default();
+		let selected_bg = theme.commit_selected(Style::default()).bg;
+
+		let lines = render_entry(
+			&entry,
+			true,
+			Some("tag"),
+			Some(true),
+			|o| {
+				o.columns = &LogColumn::ALL;
+			},
+		);
+
+		for span in &lines[0].0 {
+			assert_eq!(span.style.bg, selected_bg);
+			assert_ne!(span.style.fg, selected_bg);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "ghemoji")]
+	fn test_emoji_only_rendered() {
+		let entry = test_entry(":sparkles: feature");
+
+		let render = |emoji: bool| -> String {
+			text(&render_entry(&entry, false, None, None, |o| {
+				o.emoji = emoji;
+				o.columns = &[];
+			}))
+			.remove(0)
+		};
+
+		assert_eq!(render(true), "\u{2728} feature");
+		assert_eq!(render(false), ":sparkles: feature");
+		assert_eq!(&*entry.msg, ":sparkles: feature");
+	}
+
+	#[test]
+	fn test_bottom_border_regions() {
+		let area = Rect::new(0, 0, 20, 5);
+
+		assert_eq!(
+			CommitList::bottom_border_regions(area, 5, 4),
+			Some([
+				Rect::new(1, 4, 5, 1),
+				Rect::new(6, 4, 9, 1),
+				Rect::new(15, 4, 4, 1),
+			])
+		);
+		assert_eq!(
+			CommitList::bottom_border_regions(area, 30, 4),
+			Some([
+				Rect::new(1, 4, 18, 1),
+				Rect::new(19, 4, 0, 1),
+				Rect::new(19, 4, 0, 1),
+			])
+		);
+		assert_eq!(
+			CommitList::bottom_border_regions(
+				Rect::new(0, 0, 20, 2),
+				5,
+				4
+			),
+			None
+		);
+	}
+
+	#[test]
+	fn test_select_child() {
+		let info = |id: usize, parents: &[usize]| CommitInfo {
+			parents: parents.iter().copied().map(commit_id).collect(),
+			..commit_info(id)
+		};
+
+		let mut list = CommitList::new(
+			"",
+			SharedTheme::default(),
+			SharedKeyConfig::default(),
+			SharedLogColumns::default(),
+		);
+		// merge 1 of 2 (first parent) and 3, both children of 4
+		list.items().set_items(
+			10,
+			vec![
+				info(1, &[2, 3]),
+				info(2, &[4]),
+				info(3, &[4]),
+				info(4, &[]),
+			],
+		);
+
+		list.select_entry(13);
+		assert!(list.select_child());
+		assert_eq!(list.selection(), 12);
+		assert!(list.select_child());
+		assert_eq!(list.selection(), 10);
+		assert!(!list.select_child());
+		assert_eq!(list.selection(), 10);
+	}
+
+	#[test]
+	fn test_scroll_wrapped_entries() {
+		let info = |id: usize, message: &str| CommitInfo {
+			message: message.to_string(),
+			..commit_info(id)
+		};
+		let long = "a long commit message ".repeat(5);
+
+		let columns = SharedLogColumns::default();
+		columns.borrow_mut().toggle_wrap_message();
+		let mut list = CommitList::new(
+			"",
+			SharedTheme::default(),
+			SharedKeyConfig::default(),
+			columns,
+		);
+		// entries 0 and 3 take two lines each
+		list.items().set_items(
+			0,
+			vec![
+				info(0, &long),
+				info(1, "short"),
+				info(2, "short"),
+				info(3, &long),
+				info(4, "short"),
+				info(5, "short"),
+			],
+		);
+
+		let mut scroll_to = |selection: usize| {
+			list.select_entry(selection);
+			list.scroll_top.set(list.next_scroll_top(4, 80));
+			list.scroll_top.get()
+		};
+
+		assert_eq!(scroll_to(2), 0);
+		assert_eq!(scroll_to(3), 1);
+		assert_eq!(scroll_to(5), 3);
+		assert_eq!(scroll_to(4), 3);
+		assert_eq!(scroll_to(0), 0);
+	}
+
+	#[test]
+	fn test_tagged_position() {
+		let mut list = CommitList::new(
+			"",
+			SharedTheme::default(),
+			SharedKeyConfig::default(),
+			SharedLogColumns::default(),
+		);
+		assert_eq!(list.tagged_position(&[], true, true), None);
+
+		// unordered like the tags they come from
+		let tagged = [3, 1];
+
+		list.select_entry(0);
+		assert_eq!(
+			list.tagged_position(&tagged, true, false),
+			Some(1)
+		);
+		assert_eq!(list.tagged_position(&tagged, false, false), None);
+		assert_eq!(
+			list.tagged_position(&tagged, false, true),
+			Some(3)
+		);
+
+		list.select_entry(1);
+		assert_eq!(
+			list.tagged_position(&tagged, true, false),
+			Some(3)
+		);
+
+		list.select_entry(3);
+		assert_eq!(list.tagged_position(&tagged, true, false), None);
+		assert_eq!(
+			list.tagged_position(&tagged, true, true),
+			Some(1)
+		);
+		assert_eq!(
+			list.tagged_position(&tagged, false, false),
+			Some(1)
+		);
+	}
+
+	#[test]
+	fn test_author_rank() {
+		let info = |author: &str, id: usize| CommitInfo {
+			author: author.into(),
+			..commit_info(id)
+		};
+
+		let mut list = CommitList::new(
+			"",
+			SharedTheme::default(),
+			SharedKeyConfig::default(),
+			SharedLogColumns::default(),
+		);
+		list.items().set_items(
+			0,
+			vec![info("alice", 1), info("bob", 2), info("alice", 3)],
+		);
+
+		let ranks: Vec<usize> =
+			list.items().iter().map(|e| e.author_rank).collect();
+		assert_eq!(ranks, vec![2, 1, 1]);
+	}
+
+	#[test]
+	fn test_author_initials() {
+		let initials = |author: &str| {
+			LogEntry::from(CommitInfo {
+				author: author.into(),
+				..commit_info(0)
+			})
+			.author_initials()
+		};
+
+		assert_eq!(initials("alice"), "AL");
+		assert_eq!(initials("Jon Grythe Stødle"), "JS");
+		assert_eq!(initials("x"), "X ");
+		assert_eq!(initials(""), "  ");
+	}
+
+	#[test]
+	fn test_commit_delay() {
+		let delay = |commit_delay: i64| {
+			LogEntry::from(CommitInfo {
+				author_time: -commit_delay,
+				..commit_info(0)
+			})
+			.commit_delay_to_string()
+		};
+
+		assert_eq!(delay(0), "     ");
+		assert_eq!(delay(59), "     ");
+		assert_eq!(delay(5 * 60), "+5m  ");
+		assert_eq!(delay(3 * 3600), "+3h  ");
+		assert_eq!(delay(2 * 86400 + 3600), "+2d  ");
+		assert_eq!(delay(-86400), "-1d  ");
+		assert_eq!(delay(800 * 86400), "+2y  ");
+	}
+
+	#[test]
+	fn test_selection_stable_while_loading() {
+		let mut list = CommitList::new(
+			"",
+			SharedTheme::default(),
+			SharedKeyConfig::default(),
+			SharedLogColumns::default(),
+		);
+		let scroll = |list: &CommitList| {
+			list.scroll_top.set(list.next_scroll_top(20, 80));
+			list.scroll_top.get()
+		};
+
+		list.set_count_total(200);
+		list.items()
+			.set_items(0, (0..200).map(commit_info).collect());
+		list.select_entry(150);
+		assert_eq!(scroll(&list), 131);
+
+		// more commits got loaded, the window around the
+		// selection moves along with them
+		list.set_count_total(3000);
+		let selection_max = list.selection_max();
+		assert!(list.items().needs_data(150, selection_max));
+		list.items()
+			.set_items(50, (50..350).map(commit_info).collect());
+
+		assert_eq!(list.selection(), 150);
+		assert_eq!(
+			list.selected_entry().map(|e| e.id),
+			Some(commit_id(150))
+		);
+		assert_eq!(scroll(&list), 131);
+	}
 }
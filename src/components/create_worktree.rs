@@ -0,0 +1,146 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, CommitId, RepoPathRef};
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+/// asks for the directory of a new worktree checked out
+/// at a commit
+pub struct CreateWorktreeComponent {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	commit_id: Option<CommitId>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for CreateWorktreeComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for CreateWorktreeComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::create_worktree_confirm(
+					&self.key_config,
+				),
+				!self.input.get_text().is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.keys.enter
+					&& !self.input.get_text().is_empty()
+				{
+					self.create_worktree();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl CreateWorktreeComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::create_worktree_popup_title(),
+				&strings::create_worktree_popup_msg(),
+				true,
+			),
+			commit_id: None,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(&mut self, id: CommitId) -> Result<()> {
+		self.commit_id = Some(id);
+		self.input.clear();
+		self.show()
+	}
+
+	fn create_worktree(&mut self) {
+		if let Some(id) = self.commit_id {
+			let path = self.input.get_text().trim().to_string();
+			let res =
+				sync::add_worktree(&self.repo.borrow(), id, &path);
+
+			match res {
+				Ok(()) => {
+					self.hide();
+					self.queue.push(InternalEvent::ShowInfoMsg(
+						strings::create_worktree_success(&path),
+					));
+				}
+				Err(e) => {
+					log::error!("create worktree: {}", e);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("create worktree error:\n{}", e),
+					));
+				}
+			}
+		}
+	}
+}
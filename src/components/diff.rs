@@ -1,6 +1,6 @@
 use super::{
-	utils::scroll_vertical::VerticalScroll, CommandBlocking,
-	Direction, DrawableComponent, ScrollType,
+	utils::{scroll_vertical::VerticalScroll, word_diff},
+	CommandBlocking, Direction, DrawableComponent, ScrollType,
 };
 use crate::{
 	components::{CommandInfo, Component, EventState},
@@ -18,7 +18,7 @@ use asyncgit::{
 };
 use bytesize::ByteSize;
 use crossterm::event::Event;
-use std::{borrow::Cow, cell::Cell, cmp, path::Path};
+use std::{borrow::Cow, cell::Cell, cmp, ops::Range, path::Path};
 use tui::{
 	backend::Backend,
 	layout::Rect,
@@ -99,6 +99,7 @@ impl Selection {
 }
 
 ///
+#[allow(clippy::struct_excessive_bools)]
 pub struct DiffComponent {
 	repo: RepoPathRef,
 	diff: Option<FileDiff>,
@@ -113,6 +114,7 @@ pub struct DiffComponent {
 	theme: SharedTheme,
 	key_config: SharedKeyConfig,
 	is_immutable: bool,
+	word_diff: bool,
 }
 
 impl DiffComponent {
@@ -137,6 +139,7 @@ impl DiffComponent {
 			theme,
 			key_config,
 			is_immutable,
+			word_diff: false,
 			repo,
 		}
 	}
@@ -364,6 +367,12 @@ impl DiffComponent {
 					if Self::hunk_visible(
 						hunk_min, hunk_max, min, max,
 					) {
+						let word_changes = if self.word_diff {
+							word_diff::hunk_word_changes(&hunk.lines)
+						} else {
+							Vec::new()
+						};
+
 						for (i, line) in hunk.lines.iter().enumerate()
 						{
 							if line_cursor >= min
@@ -378,6 +387,10 @@ impl DiffComponent {
 											.contains(line_cursor),
 									hunk_selected,
 									i == hunk_len as usize - 1,
+									word_changes
+										.get(i)
+										.cloned()
+										.flatten(),
 									&self.theme,
 								));
 								lines_added += 1;
@@ -400,6 +413,7 @@ impl DiffComponent {
 		selected: bool,
 		selected_hunk: bool,
 		end_of_hunk: bool,
+		changed_words: Option<Range<usize>>,
 		theme: &SharedTheme,
 	) -> Spans<'a> {
 		let style = theme.diff_hunk_marker(selected_hunk);
@@ -427,12 +441,38 @@ impl DiffComponent {
 			format!("{}\n", line.content)
 		};
 
+		let style = theme.diff_line(line.line_type, selected);
+
+		if let Some(words) = changed_words {
+			let word_style =
+				theme.diff_word(line.line_type, selected);
+
+			return Spans::from(vec![
+				left_side_of_line,
+				Span::styled(
+					Cow::from(tabs_to_spaces(
+						filled[..words.start].to_string(),
+					)),
+					style,
+				),
+				Span::styled(
+					Cow::from(tabs_to_spaces(
+						filled[words.clone()].to_string(),
+					)),
+					word_style,
+				),
+				Span::styled(
+					Cow::from(tabs_to_spaces(
+						filled[words.end..].to_string(),
+					)),
+					style,
+				),
+			]);
+		}
+
 		Spans::from(vec![
 			left_side_of_line,
-			Span::styled(
-				Cow::from(tabs_to_spaces(filled)),
-				theme.diff_line(line.line_type, selected),
-			),
+			Span::styled(Cow::from(tabs_to_spaces(filled)), style),
 		])
 	}
 
@@ -718,6 +758,15 @@ impl Component for DiffComponent {
 			self.focused(),
 		));
 
+		out.push(CommandInfo::new(
+			strings::commands::diff_toggle_word_diff(
+				&self.key_config,
+				self.word_diff,
+			),
+			true,
+			self.focused(),
+		));
+
 		CommandBlocking::PassingOn
 	}
 
@@ -790,6 +839,9 @@ impl Component for DiffComponent {
 				} else if e == self.key_config.keys.copy {
 					self.copy_selection();
 					Ok(EventState::Consumed)
+				} else if e == self.key_config.keys.diff_word_diff {
+					self.word_diff = !self.word_diff;
+					Ok(EventState::Consumed)
 				} else {
 					Ok(EventState::NotConsumed)
 				};
@@ -0,0 +1,152 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use asyncgit::sync::{self, CommitId, RepoPathRef};
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+/// edits the message of an annotated tag,
+/// prefilled with the current one
+pub struct EditTagComponent {
+	repo: RepoPathRef,
+	input: TextInputComponent,
+	tag: Option<(CommitId, String)>,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for EditTagComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for EditTagComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::edit_tag_confirm(&self.key_config),
+				!self.input.get_text().is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.keys.enter
+					&& !self.input.get_text().is_empty()
+				{
+					self.edit_tag();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl EditTagComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			queue: queue.clone(),
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				"",
+				&strings::tag_popup_annotation_msg(),
+				true,
+			),
+			tag: None,
+			key_config,
+		}
+	}
+
+	///
+	pub fn open(
+		&mut self,
+		id: CommitId,
+		name: String,
+		annotation: String,
+	) -> Result<()> {
+		self.input.set_title(strings::edit_tag_popup_title(&name));
+		self.input.set_text(annotation);
+		self.tag = Some((id, name));
+		self.show()
+	}
+
+	fn edit_tag(&mut self) {
+		if let Some((id, name)) = &self.tag {
+			let res = sync::edit_tag_annotation(
+				&self.repo.borrow(),
+				name,
+				*id,
+				self.input.get_text(),
+			);
+
+			self.hide();
+
+			match res {
+				Ok(()) => {
+					self.queue.push(InternalEvent::TagsChanged);
+				}
+				Err(e) => {
+					log::error!("edit tag: {}", e);
+					self.queue.push(InternalEvent::ShowErrorMsg(
+						format!("edit tag error:\n{}", e),
+					));
+				}
+			}
+		}
+	}
+}
@@ -10,10 +10,9 @@ use crate::{
 	ui::{self, style::SharedTheme},
 };
 use anyhow::Result;
-use asyncgit::sync::TreeFile;
 use crossterm::event::Event;
 use fuzzy_matcher::FuzzyMatcher;
-use std::borrow::Cow;
+use std::{borrow::Cow, path::PathBuf};
 use tui::{
 	backend::Backend,
 	layout::{Constraint, Direction, Layout, Margin, Rect},
@@ -28,7 +27,7 @@ pub struct FileFindPopup {
 	find_text: TextInputComponent,
 	query: Option<String>,
 	theme: SharedTheme,
-	files: Vec<TreeFile>,
+	files: Vec<PathBuf>,
 	selection: usize,
 	selected_index: Option<usize>,
 	files_filtered: Vec<(usize, Vec<usize>)>,
@@ -93,7 +92,7 @@ impl FileFindPopup {
 				.iter()
 				.enumerate()
 				.filter_map(|a| {
-					a.1.path.to_str().and_then(|path| {
+					a.1.to_str().and_then(|path| {
 						matcher.fuzzy_indices(path, q).map(
 							|(score, indices)| (score, a.0, indices),
 						)
@@ -124,13 +123,13 @@ impl FileFindPopup {
 			let file = self
 				.selected_index
 				.and_then(|index| self.files.get(index))
-				.map(|f| f.path.clone());
+				.cloned();
 
 			self.queue.push(InternalEvent::FileFinderChanged(file));
 		}
 	}
 
-	pub fn open(&mut self, files: &[TreeFile]) -> Result<()> {
+	pub fn open(&mut self, files: &[PathBuf]) -> Result<()> {
 		self.show()?;
 		self.find_text.show()?;
 		self.find_text.set_text(String::new());
@@ -239,7 +238,6 @@ impl DrawableComponent for FileFindPopup {
 							.map_or(false, |index| index == *idx);
 						let full_text = trim_length_left(
 							self.files[*idx]
-								.path
 								.to_str()
 								.unwrap_or_default(),
 							width,
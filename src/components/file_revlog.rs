@@ -14,7 +14,8 @@ use crate::{
 use anyhow::Result;
 use asyncgit::{
 	sync::{
-		diff_contains_file, get_commits_info, CommitId, RepoPathRef,
+		self, diff_contains_file_following_renames, get_commits_info,
+		CommitId, FollowedPaths, RepoPathRef,
 	},
 	AsyncDiff, AsyncGitNotification, AsyncLog, DiffParams, DiffType,
 	FetchStatus,
@@ -36,6 +37,8 @@ const SLICE_SIZE: usize = 1200;
 pub struct FileRevOpen {
 	pub file_path: String,
 	pub selection: Option<usize>,
+	/// start the history at this commit instead of `HEAD`
+	pub commit_id: Option<CommitId>,
 }
 
 impl FileRevOpen {
@@ -43,6 +46,7 @@ impl FileRevOpen {
 		Self {
 			file_path,
 			selection: None,
+			commit_id: None,
 		}
 	}
 }
@@ -58,6 +62,9 @@ pub struct FileRevlogComponent {
 	visible: bool,
 	repo_path: RepoPathRef,
 	open_request: Option<FileRevOpen>,
+	/// path of the file in each commit, differs from the requested
+	/// path in commits before it was renamed
+	followed_paths: FollowedPaths,
 	table_state: std::cell::Cell<TableState>,
 	items: ItemBatch,
 	count_total: usize,
@@ -96,6 +103,7 @@ impl FileRevlogComponent {
 			visible: false,
 			repo_path: repo_path.clone(),
 			open_request: None,
+			followed_paths: FollowedPaths::default(),
 			table_state: std::cell::Cell::new(TableState::default()),
 			items: ItemBatch::default(),
 			count_total: 0,
@@ -112,17 +120,46 @@ impl FileRevlogComponent {
 
 	///
 	pub fn open(&mut self, open_request: FileRevOpen) -> Result<()> {
+		if let Some(commit_id) = open_request.commit_id {
+			let in_commit = sync::get_commit_files(
+				&self.repo_path.borrow(),
+				commit_id,
+				None,
+			)?
+			.iter()
+			.any(|item| item.path == open_request.file_path);
+
+			if !in_commit {
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					strings::file_log_not_in_commit(
+						&open_request.file_path,
+						commit_id,
+					),
+				));
+				return Ok(());
+			}
+		}
+
 		self.open_request = Some(open_request.clone());
 
-		let filter = diff_contains_file(
+		self.followed_paths = FollowedPaths::default();
+		let filter = diff_contains_file_following_renames(
 			self.repo_path.borrow().clone(),
 			open_request.file_path,
+			self.followed_paths.clone(),
 		);
-		self.git_log = Some(AsyncLog::new(
+		let mut git_log = AsyncLog::new(
 			self.repo_path.borrow().clone(),
 			&self.sender,
 			Some(filter),
-		));
+		);
+		// the filter follows renames from the start of the walk,
+		// so pin it instead of restarting when `HEAD` moves
+		let start = open_request.commit_id.or_else(|| {
+			sync::get_head(&self.repo_path.borrow()).ok()
+		});
+		git_log.set_start_ref(start.map(|id| id.to_string()))?;
+		self.git_log = Some(git_log);
 		self.table_state.get_mut().select(Some(0));
 		self.show()?;
 
@@ -183,12 +220,28 @@ impl FileRevlogComponent {
 		Ok(())
 	}
 
+	/// path of the file in `commit_id`, following renames
+	fn file_path_at(&self, commit_id: CommitId) -> Option<String> {
+		let followed = self
+			.followed_paths
+			.lock()
+			.ok()
+			.and_then(|paths| paths.get(&commit_id).cloned());
+
+		followed.or_else(|| {
+			self.open_request
+				.as_ref()
+				.map(|req| req.file_path.clone())
+		})
+	}
+
 	pub fn update_diff(&mut self) -> Result<()> {
 		if self.is_visible() {
 			if let Some(commit_id) = self.selected_commit() {
-				if let Some(open_request) = &self.open_request {
+				if let Some(file_path) = self.file_path_at(commit_id)
+				{
 					let diff_params = DiffParams {
-						path: open_request.file_path.clone(),
+						path: file_path.clone(),
 						diff_type: DiffType::Commit(commit_id),
 						options: self.options.borrow().diff,
 					};
@@ -197,11 +250,7 @@ impl FileRevlogComponent {
 						self.git_diff.last()?
 					{
 						if params == diff_params {
-							self.diff.update(
-								open_request.file_path.to_string(),
-								false,
-								last,
-							);
+							self.diff.update(file_path, false, last);
 
 							return Ok(());
 						}
@@ -424,8 +473,8 @@ impl FileRevlogComponent {
 			if let Some(open_request) = self.open_request.clone() {
 				self.queue.push(InternalEvent::PopupStackPush(
 					StackablePopupOpen::FileRevlog(FileRevOpen {
-						file_path: open_request.file_path,
 						selection: self.get_selection(),
+						..open_request
 					}),
 				));
 			}
@@ -502,15 +551,36 @@ impl Component for FileRevlogComponent {
 						));
 					};
 				} else if key == self.key_config.keys.blame {
-					if let Some(open_request) =
-						self.open_request.clone()
-					{
+					let commit_id = self.selected_commit();
+					let file_path = commit_id
+						.and_then(|id| self.file_path_at(id))
+						.or_else(|| {
+							self.open_request
+								.as_ref()
+								.map(|req| req.file_path.clone())
+						});
+
+					if let Some(file_path) = file_path {
+						if let Err(e) = sync::blame_check(
+							&self.repo_path.borrow(),
+							&file_path,
+							commit_id,
+						) {
+							self.queue.push(
+								InternalEvent::ShowErrorMsg(format!(
+									"blame error:\n{}",
+									e
+								)),
+							);
+							return Ok(EventState::Consumed);
+						}
+
 						self.hide_stacked(true);
 						self.queue.push(InternalEvent::OpenPopup(
 							StackablePopupOpen::BlameFile(
 								BlameFileOpen {
-									file_path: open_request.file_path,
-									commit_id: self.selected_commit(),
+									file_path,
+									commit_id,
 									selection: None,
 								},
 							),
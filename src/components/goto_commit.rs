@@ -0,0 +1,128 @@
+use super::{
+	textinput::TextInputComponent, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::style::SharedTheme,
+};
+use anyhow::Result;
+use crossterm::event::Event;
+use tui::{backend::Backend, layout::Rect, Frame};
+
+/// asks for the (1-based) position of a commit in the log to select
+pub struct GotoCommitComponent {
+	input: TextInputComponent,
+	queue: Queue,
+	key_config: SharedKeyConfig,
+}
+
+impl DrawableComponent for GotoCommitComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		rect: Rect,
+	) -> Result<()> {
+		self.input.draw(f, rect)?;
+
+		Ok(())
+	}
+}
+
+impl Component for GotoCommitComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			self.input.commands(out, force_all);
+
+			out.push(CommandInfo::new(
+				strings::commands::goto_commit_confirm(
+					&self.key_config,
+				),
+				!self.input.get_text().is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, ev: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if self.input.event(ev)?.is_consumed() {
+				return Ok(EventState::Consumed);
+			}
+
+			if let Event::Key(e) = ev {
+				if e == self.key_config.keys.enter
+					&& !self.input.get_text().is_empty()
+				{
+					self.goto();
+				}
+
+				return Ok(EventState::Consumed);
+			}
+		}
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.input.is_visible()
+	}
+
+	fn hide(&mut self) {
+		self.input.hide();
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.input.clear();
+		self.input.show()?;
+
+		Ok(())
+	}
+}
+
+impl GotoCommitComponent {
+	///
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			queue: queue.clone(),
+			input: TextInputComponent::new(
+				theme,
+				key_config.clone(),
+				&strings::goto_commit_popup_title(),
+				&strings::goto_commit_popup_msg(),
+				true,
+			),
+			key_config,
+		}
+	}
+
+	fn goto(&mut self) {
+		let text = self.input.get_text().trim();
+
+		match text.parse::<usize>() {
+			Ok(position) => {
+				self.hide();
+				self.queue.push(InternalEvent::GotoCommitPosition(
+					position,
+				));
+			}
+			Err(_) => {
+				self.queue.push(InternalEvent::ShowErrorMsg(
+					strings::goto_commit_invalid(text),
+				));
+			}
+		}
+	}
+}
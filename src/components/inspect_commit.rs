@@ -12,11 +12,15 @@ use crate::{
 };
 use anyhow::Result;
 use asyncgit::{
-	sync::{diff::DiffOptions, CommitId, CommitTags, RepoPathRef},
+	sync::{
+		diff::DiffOptions, CommitId, CommitTags, DiffParent,
+		RepoPathRef,
+	},
 	AsyncDiff, AsyncGitNotification, DiffParams, DiffType,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
+use std::path::PathBuf;
 use tui::{
 	backend::Backend,
 	layout::{Constraint, Direction, Layout, Rect},
@@ -250,6 +254,13 @@ impl InspectCommitComponent {
 		Ok(())
 	}
 
+	///
+	pub fn file_finder_update(&mut self, file: &Option<PathBuf>) {
+		if self.is_visible() {
+			self.details.file_finder_update(file);
+		}
+	}
+
 	///
 	pub fn any_work_pending(&self) -> bool {
 		self.git_diff.is_pending() || self.details.any_work_pending()
@@ -260,6 +271,10 @@ impl InspectCommitComponent {
 		&mut self,
 		ev: AsyncGitNotification,
 	) -> Result<()> {
+		if ev == AsyncGitNotification::LineStats {
+			self.details.update_line_stats();
+		}
+
 		if self.is_visible() {
 			if ev == AsyncGitNotification::CommitFiles {
 				self.update()?;
@@ -279,9 +294,17 @@ impl InspectCommitComponent {
 				{
 					let diff_params = DiffParams {
 						path: f.path.clone(),
-						diff_type: DiffType::Commit(
-							request.commit_id,
-						),
+						// a combined diff is not supported,
+						// its files show the first parent diff
+						diff_type: match self.details.diff_parent() {
+							DiffParent::Nth(idx) if idx > 0 => {
+								DiffType::CommitParent(
+									request.commit_id,
+									idx,
+								)
+							}
+							_ => DiffType::Commit(request.commit_id),
+						},
 						options: DiffOptions::default(),
 					};
 
@@ -0,0 +1,215 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	log_columns::SharedLogColumns,
+	queue::{InternalEvent, Queue},
+	strings::{self, symbol},
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::{Alignment, Rect},
+	text::{Span, Spans},
+	widgets::{Block, Borders, Clear, Paragraph},
+	Frame,
+};
+
+/// shows/hides and reorders the columns of the log,
+/// changes are saved right away
+pub struct LogColumnsPopupComponent {
+	selection: usize,
+	queue: Queue,
+	visible: bool,
+	key_config: SharedKeyConfig,
+	columns: SharedLogColumns,
+	theme: SharedTheme,
+}
+
+impl LogColumnsPopupComponent {
+	///
+	pub fn new(
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+		columns: SharedLogColumns,
+	) -> Self {
+		Self {
+			selection: 0,
+			queue: queue.clone(),
+			visible: false,
+			key_config,
+			columns,
+			theme,
+		}
+	}
+
+	fn get_text(&self) -> Vec<Spans> {
+		self.columns
+			.borrow()
+			.iter()
+			.enumerate()
+			.map(|(idx, (column, shown))| {
+				Spans::from(Span::styled(
+					format!(
+						"[{}] {}",
+						if shown {
+							symbol::CHECKMARK
+						} else {
+							symbol::EMPTY_SPACE
+						},
+						strings::log_column_name(column)
+					),
+					self.theme.text(true, idx == self.selection),
+				))
+			})
+			.collect()
+	}
+
+	fn move_selection(&mut self, up: bool) {
+		let count = self.columns.borrow().iter().count();
+		self.selection = if up {
+			self.selection.saturating_sub(1)
+		} else {
+			self.selection.saturating_add(1).min(count - 1)
+		};
+	}
+
+	fn toggle_selected(&self) {
+		let column = self
+			.columns
+			.borrow()
+			.iter()
+			.nth(self.selection)
+			.map(|(column, _)| column);
+
+		if let Some(column) = column {
+			self.columns.borrow_mut().toggle(column);
+			self.changed();
+		}
+	}
+
+	fn move_selected(&mut self, up: bool) {
+		self.selection =
+			self.columns.borrow_mut().move_column(self.selection, up);
+		self.changed();
+	}
+
+	fn changed(&self) {
+		if let Err(e) = self.columns.borrow().save() {
+			self.queue.push(InternalEvent::ShowErrorMsg(format!(
+				"saving log columns failed:\n{}",
+				e
+			)));
+		}
+
+		self.queue.push(InternalEvent::LogColumnsChanged);
+	}
+}
+
+impl DrawableComponent for LogColumnsPopupComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const SIZE: (u16, u16) = (30, 7);
+			let area =
+				ui::centered_rect_absolute(SIZE.0, SIZE.1, area);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Paragraph::new(self.get_text())
+					.block(
+						Block::default()
+							.borders(Borders::ALL)
+							.title(Span::styled(
+								strings::log_columns_title(),
+								self.theme.title(true),
+							))
+							.border_style(self.theme.block(true)),
+					)
+					.alignment(Alignment::Left),
+				area,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for LogColumnsPopupComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::log_columns_toggle(
+					&self.key_config,
+				),
+				true,
+				true,
+			));
+			out.push(CommandInfo::new(
+				strings::commands::log_columns_move(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = &event {
+				if *key == self.key_config.keys.exit_popup {
+					self.hide();
+				} else if *key == self.key_config.keys.move_up {
+					self.move_selection(true);
+				} else if *key == self.key_config.keys.move_down {
+					self.move_selection(false);
+				} else if *key == self.key_config.keys.shift_up {
+					self.move_selected(true);
+				} else if *key == self.key_config.keys.shift_down {
+					self.move_selected(false);
+				} else if *key == self.key_config.keys.enter {
+					self.toggle_selected();
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
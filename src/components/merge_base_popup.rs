@@ -0,0 +1,206 @@
+use super::{
+	utils::scroll_vertical::VerticalScroll, visibility_blocking,
+	CommandBlocking, CommandInfo, Component, DrawableComponent,
+	EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, BranchInfo, RepoPathRef};
+use crossterm::event::Event;
+use std::convert::TryFrom;
+use tui::{
+	backend::Backend,
+	layout::{Alignment, Rect},
+	text::{Span, Spans},
+	widgets::{Block, Borders, Clear, Paragraph},
+	Frame,
+};
+
+/// picks the branch whose merge-base with `HEAD`
+/// gets selected in the log
+pub struct MergeBasePopupComponent {
+	repo: RepoPathRef,
+	branches: Vec<BranchInfo>,
+	selection: usize,
+	scroll: VerticalScroll,
+	queue: Queue,
+	visible: bool,
+	key_config: SharedKeyConfig,
+	theme: SharedTheme,
+}
+
+impl MergeBasePopupComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			branches: Vec::new(),
+			selection: 0,
+			scroll: VerticalScroll::new(),
+			queue: queue.clone(),
+			visible: false,
+			key_config,
+			theme,
+		}
+	}
+
+	/// lists local and remote branches
+	pub fn open(&mut self) -> Result<()> {
+		let repo = self.repo.borrow().clone();
+		self.branches = sync::get_branches_info(&repo, true)?;
+		self.branches.extend(sync::get_branches_info(&repo, false)?);
+		self.selection = 0;
+		self.scroll.reset();
+		self.show()
+	}
+
+	fn get_text(&self, height: usize) -> Vec<Spans> {
+		self.branches
+			.iter()
+			.enumerate()
+			.skip(self.scroll.get_top())
+			.take(height)
+			.map(|(idx, branch)| {
+				Spans::from(Span::styled(
+					branch.name.as_str(),
+					self.theme.text(true, idx == self.selection),
+				))
+			})
+			.collect()
+	}
+
+	fn move_selection(&mut self, up: bool) {
+		self.selection = if up {
+			self.selection.saturating_sub(1)
+		} else {
+			self.selection
+				.saturating_add(1)
+				.min(self.branches.len().saturating_sub(1))
+		};
+	}
+
+	fn select_branch(&mut self) {
+		if let Some(branch) = self.branches.get(self.selection) {
+			self.queue.push(InternalEvent::GotoMergeBase(
+				branch.name.clone(),
+				branch.top_commit,
+			));
+			self.hide();
+		}
+	}
+}
+
+impl DrawableComponent for MergeBasePopupComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const MAX_HEIGHT: u16 = 20;
+			let height = u16::try_from(self.branches.len().max(1))?
+				.saturating_add(2)
+				.min(MAX_HEIGHT);
+			let area = ui::centered_rect_absolute(50, height, area);
+
+			let height = usize::from(area.height.saturating_sub(2));
+			self.scroll.update(
+				self.selection,
+				self.branches.len(),
+				height,
+			);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Paragraph::new(self.get_text(height))
+					.block(
+						Block::default()
+							.borders(Borders::ALL)
+							.title(Span::styled(
+								strings::merge_base_title(),
+								self.theme.title(true),
+							))
+							.border_style(self.theme.block(true)),
+					)
+					.alignment(Alignment::Left),
+				area,
+			);
+
+			self.scroll.draw(f, area, &self.theme);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for MergeBasePopupComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::merge_base_select(
+					&self.key_config,
+				),
+				!self.branches.is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = &event {
+				if *key == self.key_config.keys.exit_popup {
+					self.hide();
+				} else if *key == self.key_config.keys.move_up {
+					self.move_selection(true);
+				} else if *key == self.key_config.keys.move_down {
+					self.move_selection(false);
+				} else if *key == self.key_config.keys.enter {
+					self.select_branch();
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
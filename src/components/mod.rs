@@ -2,28 +2,37 @@ mod blame_file;
 mod branchlist;
 mod changes;
 mod command;
+mod command_palette;
 mod commit;
 mod commit_details;
 mod commitlist;
 mod compare_commits;
 mod create_branch;
+mod create_worktree;
 mod cred;
 mod diff;
+mod edit_tag;
 mod externaleditor;
 mod fetch;
 mod file_find_popup;
 mod file_revlog;
+mod goto_commit;
 mod help;
 mod inspect_commit;
+mod log_columns_popup;
+mod merge_base_popup;
 mod msg;
 mod options_popup;
 mod pull;
 mod push;
 mod push_tags;
+mod recent_commits_popup;
+mod remote_list_popup;
 mod rename_branch;
 mod reset;
 mod revision_files;
 mod revision_files_popup;
+mod squash_preview;
 mod stashmsg;
 mod status_tree;
 mod syntax_text;
@@ -37,18 +46,24 @@ pub use blame_file::{BlameFileComponent, BlameFileOpen};
 pub use branchlist::BranchListComponent;
 pub use changes::ChangesComponent;
 pub use command::{CommandInfo, CommandText};
+pub use command_palette::CommandPaletteComponent;
 pub use commit::CommitComponent;
 pub use commit_details::CommitDetailsComponent;
 pub use commitlist::CommitList;
 pub use compare_commits::CompareCommitsComponent;
 pub use create_branch::CreateBranchComponent;
+pub use create_worktree::CreateWorktreeComponent;
 pub use diff::DiffComponent;
+pub use edit_tag::EditTagComponent;
 pub use externaleditor::ExternalEditorComponent;
 pub use fetch::FetchComponent;
 pub use file_find_popup::FileFindPopup;
 pub use file_revlog::{FileRevOpen, FileRevlogComponent};
+pub use goto_commit::GotoCommitComponent;
 pub use help::HelpComponent;
 pub use inspect_commit::{InspectCommitComponent, InspectCommitOpen};
+pub use log_columns_popup::LogColumnsPopupComponent;
+pub use merge_base_popup::MergeBasePopupComponent;
 pub use msg::MsgComponent;
 pub use options_popup::{
 	AppOption, OptionsPopupComponent, SharedOptions,
@@ -56,16 +71,20 @@ pub use options_popup::{
 pub use pull::PullComponent;
 pub use push::PushComponent;
 pub use push_tags::PushTagsComponent;
+pub use recent_commits_popup::RecentCommitsPopupComponent;
+pub use remote_list_popup::RemoteListPopupComponent;
 pub use rename_branch::RenameBranchComponent;
 pub use reset::ConfirmComponent;
 pub use revision_files::RevisionFilesComponent;
 pub use revision_files_popup::{FileTreeOpen, RevisionFilesPopup};
+pub use squash_preview::SquashPreviewComponent;
 pub use stashmsg::StashMsgComponent;
 pub use syntax_text::SyntaxTextComponent;
 pub use tag_commit::TagCommitComponent;
 pub use taglist::TagListComponent;
 pub use textinput::{InputType, TextInputComponent};
 pub use utils::filetree::FileTreeItemKind;
+pub use utils::{is_weekend, time_to_string};
 
 use crate::ui::style::Theme;
 use anyhow::Result;
@@ -0,0 +1,265 @@
+use super::{
+	time_to_string, visibility_blocking, CommandBlocking,
+	CommandInfo, Component, DrawableComponent, EventState,
+	InspectCommitOpen,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue, StackablePopupOpen},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, CommitId, CommitInfo, RepoPathRef};
+use crossterm::event::Event;
+use std::convert::TryFrom;
+use tui::{
+	backend::Backend,
+	layout::{Alignment, Rect},
+	text::{Span, Spans},
+	widgets::{Block, Borders, Clear, Paragraph},
+	Frame,
+};
+
+/// how many inspected commits are remembered
+const MAX_RECENT: usize = 20;
+const MAX_MSG_WIDTH: usize = 50;
+
+/// moves `id` to the front, dropping the oldest entry beyond `MAX_RECENT`
+fn push_recent(recent: &mut Vec<CommitId>, id: CommitId) {
+	recent.retain(|other| *other != id);
+	recent.insert(0, id);
+	recent.truncate(MAX_RECENT);
+}
+
+/// lists the most recently inspected commits (newest first)
+/// to jump back to them
+pub struct RecentCommitsPopupComponent {
+	repo: RepoPathRef,
+	recent: Vec<CommitId>,
+	commits: Vec<CommitInfo>,
+	selection: usize,
+	queue: Queue,
+	visible: bool,
+	key_config: SharedKeyConfig,
+	theme: SharedTheme,
+}
+
+impl RecentCommitsPopupComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			recent: Vec::new(),
+			commits: Vec::new(),
+			selection: 0,
+			queue: queue.clone(),
+			visible: false,
+			key_config,
+			theme,
+		}
+	}
+
+	/// remember `id` as the most recently inspected commit
+	pub fn push(&mut self, id: CommitId) {
+		push_recent(&mut self.recent, id);
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.commits = sync::get_commits_info(
+			&self.repo.borrow(),
+			&self.recent,
+			MAX_MSG_WIDTH,
+		)?;
+		self.selection = 0;
+		self.show()
+	}
+
+	fn get_text(&self) -> Vec<Spans> {
+		if self.commits.is_empty() {
+			return vec![Spans::from(Span::styled(
+				strings::recent_commits_empty(),
+				self.theme.text(false, false),
+			))];
+		}
+
+		self.commits
+			.iter()
+			.enumerate()
+			.map(|(idx, commit)| {
+				let selected = idx == self.selection;
+				Spans::from(vec![
+					Span::styled(
+						commit.id.get_short_string(),
+						self.theme.commit_hash(selected),
+					),
+					Span::raw(" "),
+					Span::styled(
+						time_to_string(commit.time, true),
+						self.theme.commit_time(selected),
+					),
+					Span::raw(" "),
+					Span::styled(
+						commit.message.clone(),
+						self.theme.text(true, selected),
+					),
+				])
+			})
+			.collect()
+	}
+
+	fn move_selection(&mut self, up: bool) {
+		self.selection = if up {
+			self.selection.saturating_sub(1)
+		} else {
+			self.selection
+				.saturating_add(1)
+				.min(self.commits.len().saturating_sub(1))
+		};
+	}
+
+	fn select_commit(&mut self) {
+		if let Some(commit) = self.commits.get(self.selection) {
+			let id = commit.id;
+			self.hide();
+			self.queue.push(InternalEvent::SelectCommitInRevlog(id));
+			self.queue.push(InternalEvent::OpenPopup(
+				StackablePopupOpen::InspectCommit(
+					InspectCommitOpen::new(id),
+				),
+			));
+		}
+	}
+}
+
+impl DrawableComponent for RecentCommitsPopupComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			let height = u16::try_from(self.commits.len().max(1))?
+				.saturating_add(2);
+			let area = ui::centered_rect_absolute(80, height, area);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Paragraph::new(self.get_text())
+					.block(
+						Block::default()
+							.borders(Borders::ALL)
+							.title(Span::styled(
+								strings::recent_commits_title(),
+								self.theme.title(true),
+							))
+							.border_style(self.theme.block(true)),
+					)
+					.alignment(Alignment::Left),
+				area,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for RecentCommitsPopupComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::recent_commits_select(
+					&self.key_config,
+				),
+				!self.commits.is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = &event {
+				if *key == self.key_config.keys.exit_popup {
+					self.hide();
+				} else if *key == self.key_config.keys.move_up {
+					self.move_selection(true);
+				} else if *key == self.key_config.keys.move_down {
+					self.move_selection(false);
+				} else if *key == self.key_config.keys.enter {
+					self.select_commit();
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_push_recent() {
+		let ids: Vec<CommitId> = (0..25_u8)
+			.map(|i| {
+				CommitId::from_str_unchecked(&format!("{:040x}", i))
+					.unwrap()
+			})
+			.collect();
+
+		let mut recent = Vec::new();
+		for id in &ids {
+			push_recent(&mut recent, *id);
+		}
+
+		assert_eq!(recent.len(), MAX_RECENT);
+		assert_eq!(recent[0], ids[24]);
+		assert_eq!(recent[MAX_RECENT - 1], ids[5]);
+
+		push_recent(&mut recent, ids[10]);
+		assert_eq!(recent.len(), MAX_RECENT);
+		assert_eq!(recent[0], ids[10]);
+		assert_eq!(
+			recent.iter().filter(|id| **id == ids[10]).count(),
+			1
+		);
+	}
+}
@@ -0,0 +1,186 @@
+use super::{
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	queue::{InternalEvent, Queue},
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, RepoPathRef};
+use crossterm::event::Event;
+use std::convert::TryFrom;
+use tui::{
+	backend::Backend,
+	layout::{Alignment, Rect},
+	text::{Span, Spans},
+	widgets::{Block, Borders, Clear, Paragraph},
+	Frame,
+};
+
+/// picks the remote commit links point to
+/// if the default one is ambiguous
+pub struct RemoteListPopupComponent {
+	repo: RepoPathRef,
+	remotes: Vec<String>,
+	selection: usize,
+	queue: Queue,
+	visible: bool,
+	key_config: SharedKeyConfig,
+	theme: SharedTheme,
+}
+
+impl RemoteListPopupComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		queue: &Queue,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			remotes: Vec::new(),
+			selection: 0,
+			queue: queue.clone(),
+			visible: false,
+			key_config,
+			theme,
+		}
+	}
+
+	///
+	pub fn open(&mut self) -> Result<()> {
+		self.remotes = sync::get_remotes(&self.repo.borrow())?;
+		self.selection = 0;
+		self.show()
+	}
+
+	fn get_text(&self) -> Vec<Spans> {
+		self.remotes
+			.iter()
+			.enumerate()
+			.map(|(idx, remote)| {
+				Spans::from(Span::styled(
+					remote.as_str(),
+					self.theme.text(true, idx == self.selection),
+				))
+			})
+			.collect()
+	}
+
+	fn move_selection(&mut self, up: bool) {
+		self.selection = if up {
+			self.selection.saturating_sub(1)
+		} else {
+			self.selection
+				.saturating_add(1)
+				.min(self.remotes.len().saturating_sub(1))
+		};
+	}
+
+	fn select_remote(&mut self) {
+		if let Some(remote) = self.remotes.get(self.selection) {
+			self.queue.push(InternalEvent::UrlRemoteSelected(
+				remote.clone(),
+			));
+			self.hide();
+		}
+	}
+}
+
+impl DrawableComponent for RemoteListPopupComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			let height = u16::try_from(self.remotes.len().max(1))?
+				.saturating_add(2);
+			let area = ui::centered_rect_absolute(40, height, area);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				Paragraph::new(self.get_text())
+					.block(
+						Block::default()
+							.borders(Borders::ALL)
+							.title(Span::styled(
+								strings::remote_list_title(),
+								self.theme.title(true),
+							))
+							.border_style(self.theme.block(true)),
+					)
+					.alignment(Alignment::Left),
+				area,
+			);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for RemoteListPopupComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::remote_list_select(
+					&self.key_config,
+				),
+				!self.remotes.is_empty(),
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = &event {
+				if *key == self.key_config.keys.exit_popup {
+					self.hide();
+				} else if *key == self.key_config.keys.move_up {
+					self.move_selection(true);
+				} else if *key == self.key_config.keys.move_down {
+					self.move_selection(false);
+				} else if *key == self.key_config.keys.enter {
+					self.select_remote();
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
@@ -212,6 +212,10 @@ impl ConfirmComponent {
 				Action::AbortRevert => (
                     strings::confirm_title_abortrevert(),
                     strings::confirm_msg_revertchanges(),
+                ),
+				Action::RevertCommit(id) => (
+                    strings::confirm_title_revert_commit(),
+                    strings::confirm_msg_revert_commit(id),
                 ),
             };
 		}
@@ -172,8 +172,9 @@ impl RevisionFilesComponent {
 	}
 
 	fn open_finder(&self) {
-		self.queue
-			.push(InternalEvent::OpenFileFinder(self.files.clone()));
+		self.queue.push(InternalEvent::OpenFileFinder(
+			self.files.iter().map(|f| f.path.clone()).collect(),
+		));
 	}
 
 	pub fn find_file(&mut self, file: &Option<PathBuf>) {
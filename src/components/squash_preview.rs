@@ -0,0 +1,198 @@
+use super::{
+	dialog_paragraph, utils::scroll_vertical::VerticalScroll,
+	visibility_blocking, CommandBlocking, CommandInfo, Component,
+	DrawableComponent, EventState, ScrollType, StatusTreeComponent,
+};
+use crate::{
+	keys::SharedKeyConfig,
+	strings,
+	ui::{self, style::SharedTheme},
+};
+use anyhow::Result;
+use asyncgit::sync::{self, CommitId, RepoPathRef, SquashPreview};
+use crossterm::event::Event;
+use tui::{
+	backend::Backend,
+	layout::Rect,
+	text::{Span, Spans, Text},
+	widgets::Clear,
+	Frame,
+};
+
+/// read-only preview of squashing the range between two commits:
+/// draft message and the files changed by the range as a whole
+pub struct SquashPreviewComponent {
+	repo: RepoPathRef,
+	preview: SquashPreview,
+	scroll: VerticalScroll,
+	visible: bool,
+	key_config: SharedKeyConfig,
+	theme: SharedTheme,
+}
+
+impl SquashPreviewComponent {
+	///
+	pub fn new(
+		repo: RepoPathRef,
+		theme: SharedTheme,
+		key_config: SharedKeyConfig,
+	) -> Self {
+		Self {
+			repo,
+			preview: SquashPreview::default(),
+			scroll: VerticalScroll::new(),
+			visible: false,
+			key_config,
+			theme,
+		}
+	}
+
+	/// errors if the commits are not on one line of history
+	pub fn open(&mut self, ids: (CommitId, CommitId)) -> Result<()> {
+		self.preview =
+			sync::squash_preview(&self.repo.borrow(), ids)?;
+		self.scroll.reset();
+		self.show()
+	}
+
+	fn get_text(&self) -> Vec<Spans> {
+		let mut txt: Vec<Spans> = self
+			.preview
+			.message
+			.lines()
+			.map(|line| {
+				Spans::from(Span::styled(
+					line,
+					self.theme.text(true, false),
+				))
+			})
+			.collect();
+
+		txt.push(Spans::default());
+		txt.push(Spans::from(Span::styled(
+			strings::squash_preview_stats(&self.preview.stats),
+			self.theme.text(false, false),
+		)));
+
+		txt.extend(self.preview.files.iter().map(|file| {
+			Spans::from(Span::styled(
+				format!(
+					"{} {}",
+					StatusTreeComponent::item_status_char(
+						file.status
+					),
+					file.path
+				),
+				self.theme.item(file.status, false),
+			))
+		}));
+
+		txt
+	}
+}
+
+impl DrawableComponent for SquashPreviewComponent {
+	fn draw<B: Backend>(
+		&self,
+		f: &mut Frame<B>,
+		area: Rect,
+	) -> Result<()> {
+		if self.is_visible() {
+			const SIZE: (u16, u16) = (80, 25);
+			let area =
+				ui::centered_rect_absolute(SIZE.0, SIZE.1, area);
+
+			let txt = self.get_text();
+			let height = usize::from(area.height.saturating_sub(2));
+			self.scroll.update_no_selection(txt.len(), height);
+
+			let title =
+				strings::squash_preview_title(self.preview.count);
+
+			f.render_widget(Clear, area);
+			f.render_widget(
+				dialog_paragraph(
+					&title,
+					Text::from(
+						txt.into_iter()
+							.skip(self.scroll.get_top())
+							.collect::<Vec<_>>(),
+					),
+					&self.theme,
+					true,
+				),
+				area,
+			);
+
+			self.scroll.draw(f, area, &self.theme);
+		}
+
+		Ok(())
+	}
+}
+
+impl Component for SquashPreviewComponent {
+	fn commands(
+		&self,
+		out: &mut Vec<CommandInfo>,
+		force_all: bool,
+	) -> CommandBlocking {
+		if self.is_visible() || force_all {
+			out.push(
+				CommandInfo::new(
+					strings::commands::close_popup(&self.key_config),
+					true,
+					true,
+				)
+				.order(1),
+			);
+			out.push(CommandInfo::new(
+				strings::commands::scroll(&self.key_config),
+				true,
+				true,
+			));
+		}
+
+		visibility_blocking(self)
+	}
+
+	fn event(&mut self, event: Event) -> Result<EventState> {
+		if self.is_visible() {
+			if let Event::Key(key) = &event {
+				if *key == self.key_config.keys.exit_popup {
+					self.hide();
+				} else if *key == self.key_config.keys.move_up {
+					self.scroll.move_top(ScrollType::Up);
+				} else if *key == self.key_config.keys.move_down {
+					self.scroll.move_top(ScrollType::Down);
+				} else if *key == self.key_config.keys.shift_up
+					|| *key == self.key_config.keys.home
+				{
+					self.scroll.move_top(ScrollType::Home);
+				} else if *key == self.key_config.keys.shift_down
+					|| *key == self.key_config.keys.end
+				{
+					self.scroll.move_top(ScrollType::End);
+				}
+			}
+
+			return Ok(EventState::Consumed);
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn is_visible(&self) -> bool {
+		self.visible
+	}
+
+	fn hide(&mut self) {
+		self.visible = false;
+	}
+
+	fn show(&mut self) -> Result<()> {
+		self.visible = true;
+
+		Ok(())
+	}
+}
@@ -14,9 +14,14 @@ use crate::{
 	ui::style::SharedTheme,
 };
 use anyhow::Result;
-use asyncgit::{hash, StatusItem, StatusItemType};
+use asyncgit::{hash, sync::CommitId, StatusItem, StatusItemType};
 use crossterm::event::Event;
-use std::{borrow::Cow, cell::Cell, convert::From, path::Path};
+use std::{
+	borrow::Cow,
+	cell::Cell,
+	convert::From,
+	path::{Path, PathBuf},
+};
 use tui::{backend::Backend, layout::Rect, text::Span, Frame};
 
 //TODO: use new `filetreelist` crate
@@ -35,6 +40,8 @@ pub struct StatusTreeComponent {
 	key_config: SharedKeyConfig,
 	scroll_top: Cell<usize>,
 	visible: bool,
+	/// commit the files are part of, its file history starts there
+	commit: Option<CommitId>,
 }
 
 impl StatusTreeComponent {
@@ -58,6 +65,7 @@ impl StatusTreeComponent {
 			scroll_top: Cell::new(0),
 			pending: true,
 			visible: false,
+			commit: None,
 		}
 	}
 
@@ -104,6 +112,11 @@ impl StatusTreeComponent {
 		self.tree.tree.file_count()
 	}
 
+	///
+	pub fn set_commit(&mut self, commit: Option<CommitId>) {
+		self.commit = commit;
+	}
+
 	///
 	pub fn set_title(&mut self, title: String) {
 		self.title = title;
@@ -126,6 +139,38 @@ impl StatusTreeComponent {
 		})
 	}
 
+	/// selects `file` if it is in the list, expanding its folders
+	pub fn find_file(&mut self, file: &Option<PathBuf>) {
+		if let Some(path) = file.as_ref().and_then(|f| f.to_str()) {
+			if self.tree.select_file(path) {
+				if let Some(ref queue) = self.queue {
+					queue.push(InternalEvent::Update(
+						NeedsUpdate::DIFF,
+					));
+				}
+			}
+		}
+	}
+
+	fn open_finder(&self) {
+		if let Some(ref queue) = self.queue {
+			let files = self
+				.tree
+				.tree
+				.items()
+				.iter()
+				.filter_map(|item| match item.kind {
+					FileTreeItemKind::File(ref f) => {
+						Some(PathBuf::from(&f.path))
+					}
+					FileTreeItemKind::Path(..) => None,
+				})
+				.collect();
+
+			queue.push(InternalEvent::OpenFileFinder(files));
+		}
+	}
+
 	fn move_selection(&mut self, dir: MoveSelection) -> bool {
 		let changed = self.tree.move_selection(dir);
 
@@ -138,7 +183,7 @@ impl StatusTreeComponent {
 		changed
 	}
 
-	const fn item_status_char(item_type: StatusItemType) -> char {
+	pub const fn item_status_char(item_type: StatusItemType) -> char {
 		match item_type {
 			StatusItemType::Modified => 'M',
 			StatusItemType::New => '+',
@@ -415,6 +460,18 @@ impl Component for StatusTreeComponent {
 			)
 			.order(order::RARE_ACTION),
 		);
+		if self.commit.is_some() {
+			out.push(
+				CommandInfo::new(
+					strings::commands::find_file_in_list(
+						&self.key_config,
+					),
+					!self.is_empty(),
+					self.focused || force_all,
+				)
+				.order(order::NAV),
+			);
+		}
 
 		CommandBlocking::PassingOn
 	}
@@ -444,14 +501,22 @@ impl Component for StatusTreeComponent {
 						if let Some(queue) = &self.queue {
 							queue.push(InternalEvent::OpenPopup(
 								StackablePopupOpen::FileRevlog(
-									FileRevOpen::new(
-										status_item.path,
-									),
+									FileRevOpen {
+										commit_id: self.commit,
+										..FileRevOpen::new(
+											status_item.path,
+										)
+									},
 								),
 							));
 						}
 					}
 					Ok(EventState::Consumed)
+				} else if e == self.key_config.keys.file_list_find
+					&& self.commit.is_some()
+				{
+					self.open_finder();
+					Ok(EventState::Consumed)
 				} else if e == self.key_config.keys.move_down {
 					Ok(self
 						.move_selection(MoveSelection::Down)
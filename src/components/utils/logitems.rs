@@ -1,9 +1,6 @@
 use asyncgit::sync::{CommitId, CommitInfo};
 use chrono::{DateTime, Duration, Local, NaiveDateTime, Utc};
-use std::slice::Iter;
-
-#[cfg(feature = "ghemoji")]
-use super::emoji::emojifi_string;
+use std::{collections::HashMap, slice::Iter};
 
 static SLICE_OFFSET_RELOAD_THRESHOLD: usize = 100;
 
@@ -18,6 +15,12 @@ pub struct LogEntry {
 	//TODO: use tinyvec here
 	pub hash_short: BoxStr,
 	pub id: CommitId,
+	pub parents: Vec<CommitId>,
+	/// seconds between author and committer date
+	pub commit_delay: i64,
+	/// this is the nth commit of its author (counting from the
+	/// oldest loaded one), see `ItemBatch::set_items`
+	pub author_rank: usize,
 }
 
 impl From<CommitInfo> for LogEntry {
@@ -28,25 +31,57 @@ impl From<CommitInfo> for LogEntry {
 				Utc,
 			));
 
-		let author = c.author;
-		#[allow(unused_mut)]
-		let mut msg = c.message;
-
-		// Replace markdown emojis with Unicode equivalent
-		#[cfg(feature = "ghemoji")]
-		emojifi_string(&mut msg);
-
 		Self {
-			author: author.into(),
-			msg: msg.into(),
+			author: c.author.into(),
+			msg: c.message.into(),
 			time,
 			hash_short: c.id.get_short_string().into(),
 			id: c.id,
+			parents: c.parents,
+			commit_delay: c.time - c.author_time,
+			author_rank: 0,
 		}
 	}
 }
 
 impl LogEntry {
+	/// two uppercase letters: first and last word of multi-word
+	/// names, the first two letters of single-word ones
+	pub fn author_initials(&self) -> String {
+		let mut words = self.author.split_whitespace();
+		let first = words.next().unwrap_or_default();
+		let initials: String = words.last().map_or_else(
+			|| first.chars().take(2).collect(),
+			|last| {
+				first
+					.chars()
+					.take(1)
+					.chain(last.chars().take(1))
+					.collect()
+			},
+		);
+
+		format!("{:<2}", initials.to_uppercase())
+	}
+
+	/// like `+2d`, blank if committed within a minute of authoring
+	pub fn commit_delay_to_string(&self) -> String {
+		let delay = Duration::seconds(self.commit_delay);
+		let delay_str = if delay.num_minutes() == 0 {
+			String::new()
+		} else if delay.num_hours() == 0 {
+			format!("{:+}m", delay.num_minutes())
+		} else if delay.num_days() == 0 {
+			format!("{:+}h", delay.num_hours())
+		} else if delay.num_days().abs() < 365 {
+			format!("{:+}d", delay.num_days())
+		} else {
+			format!("{:+}y", delay.num_days() / 365)
+		};
+
+		format!("{: <5}", delay_str)
+	}
+
 	pub fn time_to_string(&self, now: DateTime<Local>) -> String {
 		let delta = now - self.time;
 		if delta < Duration::minutes(30) {
@@ -81,6 +116,32 @@ impl ItemBatch {
 		self.index_offset
 	}
 
+	/// true if the item at the absolute index `idx` is loaded
+	pub fn contains_index(&self, idx: usize) -> bool {
+		idx >= self.index_offset && idx < self.last_idx()
+	}
+
+	/// loaded item at the absolute index `idx`
+	pub fn get(&self, idx: usize) -> Option<&LogEntry> {
+		if self.contains_index(idx) {
+			self.items.get(idx - self.index_offset)
+		} else {
+			None
+		}
+	}
+
+	/// true if `idx` is at or past the last loaded item while more
+	/// of the `total` items exist or are still `pending`
+	pub fn more_below(
+		&self,
+		idx: usize,
+		total: usize,
+		pending: bool,
+	) -> bool {
+		idx.saturating_add(1) >= self.last_idx()
+			&& (pending || self.last_idx() < total)
+	}
+
 	/// shortcut to get an `Iter` of our internal items
 	pub fn iter(&self) -> Iter<'_, LogEntry> {
 		self.items.iter()
@@ -100,6 +161,25 @@ impl ItemBatch {
 		self.items.clear();
 		self.items.extend(commits.into_iter().map(LogEntry::from));
 		self.index_offset = start_index;
+
+		let mut counts = HashMap::<&str, usize>::new();
+		for e in self.items.iter_mut().rev() {
+			let count = counts.entry(&e.author).or_default();
+			*count += 1;
+			e.author_rank = *count;
+		}
+	}
+
+	/// index of the closest loaded commit newer than the one at `idx`
+	/// that has it as a parent
+	pub fn child_index(&self, idx: usize) -> Option<usize> {
+		let rel_idx = idx.checked_sub(self.index_offset)?;
+		let id = self.items.get(rel_idx)?.id;
+
+		self.items[..rel_idx]
+			.iter()
+			.rposition(|e| e.parents.contains(&id))
+			.map(|child| child + self.index_offset)
 	}
 
 	/// returns `true` if we should fetch updated list of items
@@ -117,10 +197,61 @@ impl ItemBatch {
 }
 
 #[cfg(test)]
-#[cfg(feature = "ghemoji")]
-mod tests {
+pub mod tests {
+	#[cfg(feature = "ghemoji")]
+	use super::super::emoji::emojifi_string;
 	use super::*;
 
+	/// `id` as the hex digits of a full hash
+	pub fn commit_id(id: usize) -> CommitId {
+		CommitId::from_str_unchecked(&format!("{:040x}", id)).unwrap()
+	}
+
+	/// a commit with nothing but its id, see `commit_id`
+	pub fn commit_info(id: usize) -> CommitInfo {
+		CommitInfo {
+			message: String::new(),
+			time: 0,
+			author_time: 0,
+			author: String::new(),
+			id: commit_id(id),
+			parents: Vec::new(),
+		}
+	}
+
+	#[test]
+	fn test_contains_index() {
+		let mut batch = ItemBatch::default();
+		assert!(!batch.contains_index(0));
+		assert!(batch.get(0).is_none());
+
+		batch.set_items(10, vec![commit_info(1), commit_info(2)]);
+
+		assert!(!batch.contains_index(9));
+		assert!(batch.contains_index(10));
+		assert!(batch.contains_index(11));
+		assert!(!batch.contains_index(12));
+
+		assert!(batch.get(9).is_none());
+		assert_eq!(batch.get(11).map(|e| e.id), Some(commit_id(2)));
+		assert!(batch.get(12).is_none());
+	}
+
+	#[test]
+	fn test_more_below() {
+		let mut batch = ItemBatch::default();
+		assert!(batch.more_below(0, 0, true));
+		assert!(!batch.more_below(0, 0, false));
+
+		batch.set_items(10, vec![commit_info(1), commit_info(2)]);
+
+		assert!(!batch.more_below(10, 20, false));
+		assert!(batch.more_below(11, 20, false));
+		assert!(!batch.more_below(11, 12, false));
+		assert!(batch.more_below(11, 12, true));
+	}
+
+	#[cfg(feature = "ghemoji")]
 	fn test_conversion(s: &str) -> String {
 		let mut s = s.to_string();
 		emojifi_string(&mut s);
@@ -128,6 +259,7 @@ mod tests {
 	}
 
 	#[test]
+	#[cfg(feature = "ghemoji")]
 	fn test_emojifi_string_conversion_cases() {
 		assert_eq!(
 			&test_conversion("It's :hammer: time!"),
@@ -145,6 +277,7 @@ mod tests {
 	}
 
 	#[test]
+	#[cfg(feature = "ghemoji")]
 	fn test_emojifi_string_no_conversion_cases() {
 		assert_eq!(&test_conversion("123"), "123");
 		assert_eq!(
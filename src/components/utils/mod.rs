@@ -1,4 +1,6 @@
-use chrono::{DateTime, Local, NaiveDateTime, Utc};
+use chrono::{
+	DateTime, Datelike, Local, NaiveDateTime, Utc, Weekday,
+};
 use unicode_width::UnicodeWidthStr;
 
 #[cfg(feature = "ghemoji")]
@@ -7,6 +9,7 @@ pub mod filetree;
 pub mod logitems;
 pub mod scroll_vertical;
 pub mod statustree;
+pub mod word_diff;
 
 /// macro to simplify running code that might return Err.
 /// It will show a popup in that case
@@ -55,7 +58,34 @@ pub fn string_width_align(s: &str, width: usize) -> String {
 	}
 }
 
+/// true if unix time `secs` is on a saturday or sunday in local timezone
+pub fn is_weekend(secs: i64) -> bool {
+	let time = DateTime::<Local>::from(DateTime::<Utc>::from_utc(
+		NaiveDateTime::from_timestamp(secs, 0),
+		Utc,
+	));
+	matches!(time.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
 #[inline]
 fn find_truncate_point(s: &str, chars: usize) -> usize {
 	s.chars().take(chars).map(char::len_utf8).sum()
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_is_weekend() {
+		// noon UTC so every common timezone stays on the same day
+		// 2021-01-01 friday
+		assert!(!is_weekend(1_609_502_400));
+		// 2021-01-02 saturday
+		assert!(is_weekend(1_609_588_800));
+		// 2021-01-03 sunday
+		assert!(is_weekend(1_609_675_200));
+		// 2021-01-04 monday
+		assert!(!is_weekend(1_609_761_600));
+	}
+}
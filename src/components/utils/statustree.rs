@@ -164,6 +164,34 @@ impl StatusTree {
 		self.tree.items().is_empty()
 	}
 
+	/// selects the file at `path`, expanding all collapsed
+	/// folders it is in. returns true if the selection changed
+	pub fn select_file(&mut self, path: &str) -> bool {
+		let index = self.tree.items().iter().position(|item| {
+			matches!(item.kind, FileTreeItemKind::File(_))
+				&& item.info.full_path == path
+		});
+
+		index.map_or(false, |index| {
+			// outer folders first so inner ones see the new visibility
+			for i in 0..index {
+				let item = &self.tree[i];
+				if matches!(item.kind, FileTreeItemKind::Path(PathCollapsed(collapsed)) if collapsed)
+					&& path.starts_with(&format!(
+						"{}/",
+						item.info.full_path
+					)) {
+					let folder = item.info.full_path.clone();
+					self.expand(&folder, i);
+				}
+			}
+
+			let changed = self.selection != Some(index);
+			self.selection = Some(index);
+			changed
+		})
+	}
+
 	fn all_collapsed(&self) -> BTreeSet<&String> {
 		let mut res = BTreeSet::new();
 
@@ -762,6 +790,32 @@ mod tests {
 		assert_eq!(res.selection, Some(3));
 	}
 
+	#[test]
+	fn test_select_file() {
+		let items = string_vec_to_status(&[
+			"a/b/c", //
+			"a/d",   //
+		]);
+
+		//0 a/
+		//1   b/
+		//2     c
+		//3   d
+
+		let mut res = StatusTree::default();
+		res.update(&items).unwrap();
+		res.collapse(&String::from("a/b"), 1);
+		res.collapse(&String::from("a"), 0);
+
+		assert!(!res.select_file("a/b"));
+		assert!(res.select_file("a/b/c"));
+		assert_eq!(res.selection, Some(2));
+		assert_eq!(get_visibles(&res), vec![true, true, true, true]);
+
+		assert!(!res.select_file("a/b/c"));
+		assert!(!res.select_file("x"));
+	}
+
 	#[test]
 	fn test_folders_fold_up_if_alone_in_directory() {
 		let items = string_vec_to_status(&[
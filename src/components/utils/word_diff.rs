@@ -0,0 +1,174 @@
+use asyncgit::{DiffLine, DiffLineType};
+use std::ops::Range;
+
+/// byte ranges of the words that changed in each line of a hunk,
+/// `None` for lines without a counterpart to compare to.
+///
+/// every run of deleted lines directly followed by added lines is
+/// paired up line by line (first deleted with first added etc.)
+pub fn hunk_word_changes(
+	lines: &[DiffLine],
+) -> Vec<Option<Range<usize>>> {
+	let mut res = vec![None; lines.len()];
+
+	let mut i = 0;
+	while i < lines.len() {
+		let deleted = run_len(&lines[i..], DiffLineType::Delete);
+		let added = run_len(&lines[i + deleted..], DiffLineType::Add);
+
+		for n in 0..deleted.min(added) {
+			let old = i + n;
+			let new = i + deleted + n;
+
+			if let Some((old_range, new_range)) = changed_words(
+				&lines[old].content,
+				&lines[new].content,
+			) {
+				res[old] = Some(old_range);
+				res[new] = Some(new_range);
+			}
+		}
+
+		i += (deleted + added).max(1);
+	}
+
+	res
+}
+
+fn run_len(lines: &[DiffLine], line_type: DiffLineType) -> usize {
+	lines
+		.iter()
+		.take_while(|l| l.line_type == line_type)
+		.count()
+}
+
+/// the ranges in between the common leading and trailing words of
+/// both lines, `None` if nothing is in common (or nothing changed)
+fn changed_words(
+	old: &str,
+	new: &str,
+) -> Option<(Range<usize>, Range<usize>)> {
+	let old_words = words(old);
+	let new_words = words(new);
+
+	let prefix = old_words
+		.iter()
+		.zip(&new_words)
+		.take_while(|(o, n)| o.1 == n.1)
+		.count();
+
+	let max_suffix = old_words.len().min(new_words.len()) - prefix;
+	let suffix = old_words
+		.iter()
+		.rev()
+		.zip(new_words.iter().rev())
+		.take(max_suffix)
+		.take_while(|(o, n)| o.1 == n.1)
+		.count();
+
+	if prefix + suffix == 0
+		|| (old_words.len() == new_words.len()
+			&& prefix == old_words.len())
+	{
+		return None;
+	}
+
+	let range = |text: &str, words: &[(usize, &str)]| {
+		let start = words.get(prefix).map_or(text.len(), |w| w.0);
+		let end = words
+			.len()
+			.checked_sub(suffix)
+			.and_then(|i| words.get(i))
+			.map_or(text.len(), |w| w.0);
+		start..end.max(start)
+	};
+
+	Some((range(old, &old_words), range(new, &new_words)))
+}
+
+/// splits into runs of alphanumeric chars, runs of whitespace and
+/// single other chars, keeping the byte offset of each
+fn words(text: &str) -> Vec<(usize, &str)> {
+	#[derive(PartialEq)]
+	enum Class {
+		Word,
+		Space,
+		Other,
+	}
+
+	let class = |c: char| {
+		if c.is_alphanumeric() || c == '_' {
+			Class::Word
+		} else if c.is_whitespace() {
+			Class::Space
+		} else {
+			Class::Other
+		}
+	};
+
+	let mut res = Vec::new();
+	let mut start = 0;
+	let mut last: Option<Class> = None;
+
+	for (i, c) in text.char_indices() {
+		let current = class(c);
+		let split = last.as_ref().map_or(false, |last| {
+			*last != current || current == Class::Other
+		});
+		if split {
+			res.push((start, &text[start..i]));
+			start = i;
+		}
+		last = Some(current);
+	}
+
+	if start < text.len() {
+		res.push((start, &text[start..]));
+	}
+
+	res
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn line(line_type: DiffLineType, content: &str) -> DiffLine {
+		DiffLine {
+			content: content.into(),
+			line_type,
+			..DiffLine::default()
+		}
+	}
+
+	#[test]
+	fn test_changed_words() {
+		assert_eq!(
+			changed_words("let foo = 1;", "let bar = 1;"),
+			Some((4..7, 4..7))
+		);
+		assert_eq!(
+			changed_words("a(b)", "a(b, c)"),
+			Some((3..3, 3..6))
+		);
+		assert_eq!(changed_words("foo", "bar"), None);
+		assert_eq!(changed_words("same", "same"), None);
+	}
+
+	#[test]
+	fn test_hunk_word_changes() {
+		let lines = vec![
+			line(DiffLineType::Header, "@@ -1,3 +1,3 @@"),
+			line(DiffLineType::None, "fn main() {"),
+			line(DiffLineType::Delete, "\tlet x = 1;"),
+			line(DiffLineType::Add, "\tlet y = 1;"),
+			line(DiffLineType::Add, "\tnew line"),
+			line(DiffLineType::None, "}"),
+		];
+
+		assert_eq!(
+			hunk_word_changes(&lines),
+			vec![None, None, Some(5..6), Some(5..6), None, None]
+		);
+	}
+}
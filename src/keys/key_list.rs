@@ -70,16 +70,64 @@ pub struct KeysList {
 	pub push: KeyEvent,
 	pub open_file_tree: KeyEvent,
 	pub file_find: KeyEvent,
+	pub file_list_find: KeyEvent,
 	pub force_push: KeyEvent,
 	pub pull: KeyEvent,
 	pub abort_merge: KeyEvent,
 	pub undo_commit: KeyEvent,
 	pub stage_unstage_item: KeyEvent,
 	pub tag_annotate: KeyEvent,
+	pub log_copy_markdown_link: KeyEvent,
+	pub log_copy_date: KeyEvent,
+	pub log_copy_author_hashes: KeyEvent,
+	pub log_copy_revert_command: KeyEvent,
+	pub log_first_parent: KeyEvent,
+	pub blame_heatmap: KeyEvent,
+	pub log_max_commits: KeyEvent,
+	pub log_since: KeyEvent,
+	pub log_author_initials: KeyEvent,
+	pub log_describe: KeyEvent,
+	pub log_columns: KeyEvent,
+	pub log_recent_commits: KeyEvent,
+	pub log_hide_empty: KeyEvent,
+	pub log_goto_position: KeyEvent,
+	pub select_parent: KeyEvent,
+	pub inspect_parent: KeyEvent,
+	pub toggle_ansi: KeyEvent,
+	pub diff_parent: KeyEvent,
+	pub log_command_palette: KeyEvent,
+	pub log_only_conflicted: KeyEvent,
+	pub log_pin_commit: KeyEvent,
+	pub log_emoji: KeyEvent,
+	pub log_next_tagged: KeyEvent,
+	pub log_prev_tagged: KeyEvent,
+	pub log_squash_preview: KeyEvent,
+	pub log_copy_range_subjects: KeyEvent,
+	pub log_wrap_message: KeyEvent,
+	pub log_oneline: KeyEvent,
+	pub log_full_hash: KeyEvent,
+	pub log_topo_order: KeyEvent,
+	pub log_no_merges: KeyEvent,
+	pub log_weekend: KeyEvent,
+	pub log_merge_base: KeyEvent,
+	pub log_create_worktree: KeyEvent,
+	pub log_edit_tag: KeyEvent,
+	pub log_toggle_details_focus: KeyEvent,
+	pub log_inline_tags: KeyEvent,
+	pub log_goto_parent: KeyEvent,
+	pub log_goto_child: KeyEvent,
+	pub log_author_rank: KeyEvent,
+	pub log_copy_tree_hash: KeyEvent,
+	pub log_copy_full_hash: KeyEvent,
+	pub log_signatures: KeyEvent,
+	pub log_commit_delay: KeyEvent,
+	pub log_reload: KeyEvent,
+	pub diff_word_diff: KeyEvent,
 }
 
 #[rustfmt::skip]
 impl Default for KeysList {
+	#[allow(clippy::too_many_lines)]
 	fn default() -> Self {
 		Self {
 			tab_status: KeyEvent { code: KeyCode::Char('1'), modifiers: KeyModifiers::empty()},
@@ -152,8 +200,55 @@ impl Default for KeysList {
 			abort_merge: KeyEvent { code: KeyCode::Char('A'), modifiers: KeyModifiers::SHIFT},
 			open_file_tree: KeyEvent { code: KeyCode::Char('F'), modifiers: KeyModifiers::SHIFT},
 			file_find: KeyEvent { code: KeyCode::Char('f'), modifiers: KeyModifiers::empty()},
+			file_list_find: KeyEvent { code: KeyCode::Char('/'), modifiers: KeyModifiers::empty()},
 			stage_unstage_item: KeyEvent { code: KeyCode::Enter, modifiers: KeyModifiers::empty()},
 			tag_annotate: KeyEvent { code: KeyCode::Char('a'), modifiers: KeyModifiers::CONTROL},
+			log_copy_markdown_link: KeyEvent { code: KeyCode::Char('L'), modifiers: KeyModifiers::SHIFT},
+			log_copy_date: KeyEvent { code: KeyCode::Char('d'), modifiers: KeyModifiers::CONTROL},
+			log_copy_author_hashes: KeyEvent { code: KeyCode::Char('y'), modifiers: KeyModifiers::CONTROL},
+			log_copy_revert_command: KeyEvent { code: KeyCode::Char('r'), modifiers: KeyModifiers::CONTROL},
+			log_first_parent: KeyEvent { code: KeyCode::Char('f'), modifiers: KeyModifiers::empty()},
+			blame_heatmap: KeyEvent { code: KeyCode::Char('a'), modifiers: KeyModifiers::empty()},
+			log_max_commits: KeyEvent { code: KeyCode::Char('M'), modifiers: KeyModifiers::SHIFT},
+			log_since: KeyEvent { code: KeyCode::Char('S'), modifiers: KeyModifiers::SHIFT},
+			log_author_initials: KeyEvent { code: KeyCode::Char('I'), modifiers: KeyModifiers::SHIFT},
+			log_describe: KeyEvent { code: KeyCode::Char('d'), modifiers: KeyModifiers::empty()},
+			log_columns: KeyEvent { code: KeyCode::Char('O'), modifiers: KeyModifiers::SHIFT},
+			log_recent_commits: KeyEvent { code: KeyCode::Char('v'), modifiers: KeyModifiers::empty()},
+			log_hide_empty: KeyEvent { code: KeyCode::Char('E'), modifiers: KeyModifiers::SHIFT},
+			log_goto_position: KeyEvent { code: KeyCode::Char('#'), modifiers: KeyModifiers::empty()},
+			select_parent: KeyEvent { code: KeyCode::Char('^'), modifiers: KeyModifiers::empty()},
+			inspect_parent: KeyEvent { code: KeyCode::Char('~'), modifiers: KeyModifiers::empty()},
+			toggle_ansi: KeyEvent { code: KeyCode::Char('x'), modifiers: KeyModifiers::empty()},
+			diff_parent: KeyEvent { code: KeyCode::Char('%'), modifiers: KeyModifiers::empty()},
+			log_command_palette: KeyEvent { code: KeyCode::Char('p'), modifiers: KeyModifiers::CONTROL},
+			log_only_conflicted: KeyEvent { code: KeyCode::Char('X'), modifiers: KeyModifiers::SHIFT},
+			log_pin_commit: KeyEvent { code: KeyCode::Char('*'), modifiers: KeyModifiers::empty()},
+			log_emoji: KeyEvent { code: KeyCode::Char(':'), modifiers: KeyModifiers::empty()},
+			log_next_tagged: KeyEvent { code: KeyCode::Char(']'), modifiers: KeyModifiers::empty()},
+			log_prev_tagged: KeyEvent { code: KeyCode::Char('['), modifiers: KeyModifiers::empty()},
+			log_squash_preview: KeyEvent { code: KeyCode::Char('Q'), modifiers: KeyModifiers::SHIFT},
+			log_copy_range_subjects: KeyEvent { code: KeyCode::Char('s'), modifiers: KeyModifiers::CONTROL},
+			log_wrap_message: KeyEvent { code: KeyCode::Char('z'), modifiers: KeyModifiers::empty()},
+			log_oneline: KeyEvent { code: KeyCode::Char('o'), modifiers: KeyModifiers::CONTROL},
+			log_full_hash: KeyEvent { code: KeyCode::Char('g'), modifiers: KeyModifiers::CONTROL},
+			log_topo_order: KeyEvent { code: KeyCode::Char('t'), modifiers: KeyModifiers::CONTROL},
+			log_no_merges: KeyEvent { code: KeyCode::Char('n'), modifiers: KeyModifiers::CONTROL},
+			log_weekend: KeyEvent { code: KeyCode::Char('K'), modifiers: KeyModifiers::SHIFT},
+			log_merge_base: KeyEvent { code: KeyCode::Char('b'), modifiers: KeyModifiers::CONTROL},
+			log_create_worktree: KeyEvent { code: KeyCode::Char('w'), modifiers: KeyModifiers::CONTROL},
+			log_edit_tag: KeyEvent { code: KeyCode::Char('e'), modifiers: KeyModifiers::CONTROL},
+			log_toggle_details_focus: KeyEvent { code: KeyCode::Char('V'), modifiers: KeyModifiers::SHIFT},
+			log_inline_tags: KeyEvent { code: KeyCode::Char('i'), modifiers: KeyModifiers::empty()},
+			log_goto_parent: KeyEvent { code: KeyCode::Char('P'), modifiers: KeyModifiers::SHIFT},
+			log_goto_child: KeyEvent { code: KeyCode::Char('N'), modifiers: KeyModifiers::SHIFT},
+			log_author_rank: KeyEvent { code: KeyCode::Char('r'), modifiers: KeyModifiers::empty()},
+			log_copy_tree_hash: KeyEvent { code: KeyCode::Char('Y'), modifiers: KeyModifiers::SHIFT},
+			log_copy_full_hash: KeyEvent { code: KeyCode::Char('f'), modifiers: KeyModifiers::CONTROL},
+			log_signatures: KeyEvent { code: KeyCode::Char('g'), modifiers: KeyModifiers::empty()},
+			log_commit_delay: KeyEvent { code: KeyCode::Char('+'), modifiers: KeyModifiers::empty()},
+			log_reload: KeyEvent { code: KeyCode::Char('R'), modifiers: KeyModifiers::SHIFT},
+			diff_word_diff: KeyEvent { code: KeyCode::Char('W'), modifiers: KeyModifiers::SHIFT},
 		}
 	}
 }
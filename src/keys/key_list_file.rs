@@ -74,12 +74,59 @@ pub struct KeysListFile {
 	pub push: Option<KeyEvent>,
 	pub open_file_tree: Option<KeyEvent>,
 	pub file_find: Option<KeyEvent>,
+	pub file_list_find: Option<KeyEvent>,
 	pub force_push: Option<KeyEvent>,
 	pub pull: Option<KeyEvent>,
 	pub abort_merge: Option<KeyEvent>,
 	pub undo_commit: Option<KeyEvent>,
 	pub stage_unstage_item: Option<KeyEvent>,
 	pub tag_annotate: Option<KeyEvent>,
+	pub log_copy_markdown_link: Option<KeyEvent>,
+	pub log_copy_date: Option<KeyEvent>,
+	pub log_copy_author_hashes: Option<KeyEvent>,
+	pub log_copy_revert_command: Option<KeyEvent>,
+	pub log_first_parent: Option<KeyEvent>,
+	pub blame_heatmap: Option<KeyEvent>,
+	pub log_max_commits: Option<KeyEvent>,
+	pub log_since: Option<KeyEvent>,
+	pub log_author_initials: Option<KeyEvent>,
+	pub log_describe: Option<KeyEvent>,
+	pub log_columns: Option<KeyEvent>,
+	pub log_recent_commits: Option<KeyEvent>,
+	pub log_hide_empty: Option<KeyEvent>,
+	pub log_goto_position: Option<KeyEvent>,
+	pub select_parent: Option<KeyEvent>,
+	pub inspect_parent: Option<KeyEvent>,
+	pub toggle_ansi: Option<KeyEvent>,
+	pub diff_parent: Option<KeyEvent>,
+	pub log_command_palette: Option<KeyEvent>,
+	pub log_only_conflicted: Option<KeyEvent>,
+	pub log_pin_commit: Option<KeyEvent>,
+	pub log_emoji: Option<KeyEvent>,
+	pub log_next_tagged: Option<KeyEvent>,
+	pub log_prev_tagged: Option<KeyEvent>,
+	pub log_squash_preview: Option<KeyEvent>,
+	pub log_copy_range_subjects: Option<KeyEvent>,
+	pub log_wrap_message: Option<KeyEvent>,
+	pub log_oneline: Option<KeyEvent>,
+	pub log_full_hash: Option<KeyEvent>,
+	pub log_topo_order: Option<KeyEvent>,
+	pub log_no_merges: Option<KeyEvent>,
+	pub log_weekend: Option<KeyEvent>,
+	pub log_merge_base: Option<KeyEvent>,
+	pub log_create_worktree: Option<KeyEvent>,
+	pub log_edit_tag: Option<KeyEvent>,
+	pub log_toggle_details_focus: Option<KeyEvent>,
+	pub log_inline_tags: Option<KeyEvent>,
+	pub log_goto_parent: Option<KeyEvent>,
+	pub log_goto_child: Option<KeyEvent>,
+	pub log_author_rank: Option<KeyEvent>,
+	pub log_copy_tree_hash: Option<KeyEvent>,
+	pub log_copy_full_hash: Option<KeyEvent>,
+	pub log_signatures: Option<KeyEvent>,
+	pub log_commit_delay: Option<KeyEvent>,
+	pub log_reload: Option<KeyEvent>,
+	pub diff_word_diff: Option<KeyEvent>,
 }
 
 impl KeysListFile {
@@ -91,6 +138,7 @@ impl KeysListFile {
 	}
 
 	#[rustfmt::skip]
+	#[allow(clippy::too_many_lines)]
 	pub fn get_list(self) -> KeysList {
 		let default = KeysList::default();
 
@@ -161,12 +209,59 @@ impl KeysListFile {
 			push: self.push.unwrap_or(default.push),
 			open_file_tree: self.open_file_tree.unwrap_or(default.open_file_tree),
 			file_find: self.file_find.unwrap_or(default.file_find),
+			file_list_find: self.file_list_find.unwrap_or(default.file_list_find),
 			force_push: self.force_push.unwrap_or(default.force_push),
 			pull: self.pull.unwrap_or(default.pull),
 			abort_merge: self.abort_merge.unwrap_or(default.abort_merge),
 			undo_commit: self.undo_commit.unwrap_or(default.undo_commit),
 			stage_unstage_item: self.stage_unstage_item.unwrap_or(default.stage_unstage_item),
 			tag_annotate: self.tag_annotate.unwrap_or(default.tag_annotate),
+			log_copy_markdown_link: self.log_copy_markdown_link.unwrap_or(default.log_copy_markdown_link),
+			log_copy_date: self.log_copy_date.unwrap_or(default.log_copy_date),
+			log_copy_author_hashes: self.log_copy_author_hashes.unwrap_or(default.log_copy_author_hashes),
+			log_copy_revert_command: self.log_copy_revert_command.unwrap_or(default.log_copy_revert_command),
+			log_first_parent: self.log_first_parent.unwrap_or(default.log_first_parent),
+			blame_heatmap: self.blame_heatmap.unwrap_or(default.blame_heatmap),
+			log_max_commits: self.log_max_commits.unwrap_or(default.log_max_commits),
+			log_since: self.log_since.unwrap_or(default.log_since),
+			log_author_initials: self.log_author_initials.unwrap_or(default.log_author_initials),
+			log_describe: self.log_describe.unwrap_or(default.log_describe),
+			log_columns: self.log_columns.unwrap_or(default.log_columns),
+			log_recent_commits: self.log_recent_commits.unwrap_or(default.log_recent_commits),
+			log_hide_empty: self.log_hide_empty.unwrap_or(default.log_hide_empty),
+			log_goto_position: self.log_goto_position.unwrap_or(default.log_goto_position),
+			select_parent: self.select_parent.unwrap_or(default.select_parent),
+			inspect_parent: self.inspect_parent.unwrap_or(default.inspect_parent),
+			toggle_ansi: self.toggle_ansi.unwrap_or(default.toggle_ansi),
+			diff_parent: self.diff_parent.unwrap_or(default.diff_parent),
+			log_command_palette: self.log_command_palette.unwrap_or(default.log_command_palette),
+			log_only_conflicted: self.log_only_conflicted.unwrap_or(default.log_only_conflicted),
+			log_pin_commit: self.log_pin_commit.unwrap_or(default.log_pin_commit),
+			log_emoji: self.log_emoji.unwrap_or(default.log_emoji),
+			log_next_tagged: self.log_next_tagged.unwrap_or(default.log_next_tagged),
+			log_prev_tagged: self.log_prev_tagged.unwrap_or(default.log_prev_tagged),
+			log_squash_preview: self.log_squash_preview.unwrap_or(default.log_squash_preview),
+			log_copy_range_subjects: self.log_copy_range_subjects.unwrap_or(default.log_copy_range_subjects),
+			log_wrap_message: self.log_wrap_message.unwrap_or(default.log_wrap_message),
+			log_oneline: self.log_oneline.unwrap_or(default.log_oneline),
+			log_full_hash: self.log_full_hash.unwrap_or(default.log_full_hash),
+			log_topo_order: self.log_topo_order.unwrap_or(default.log_topo_order),
+			log_no_merges: self.log_no_merges.unwrap_or(default.log_no_merges),
+			log_weekend: self.log_weekend.unwrap_or(default.log_weekend),
+			log_merge_base: self.log_merge_base.unwrap_or(default.log_merge_base),
+			log_create_worktree: self.log_create_worktree.unwrap_or(default.log_create_worktree),
+			log_edit_tag: self.log_edit_tag.unwrap_or(default.log_edit_tag),
+			log_toggle_details_focus: self.log_toggle_details_focus.unwrap_or(default.log_toggle_details_focus),
+			log_inline_tags: self.log_inline_tags.unwrap_or(default.log_inline_tags),
+			log_goto_parent: self.log_goto_parent.unwrap_or(default.log_goto_parent),
+			log_goto_child: self.log_goto_child.unwrap_or(default.log_goto_child),
+			log_author_rank: self.log_author_rank.unwrap_or(default.log_author_rank),
+			log_copy_tree_hash: self.log_copy_tree_hash.unwrap_or(default.log_copy_tree_hash),
+			log_copy_full_hash: self.log_copy_full_hash.unwrap_or(default.log_copy_full_hash),
+			log_signatures: self.log_signatures.unwrap_or(default.log_signatures),
+			log_commit_delay: self.log_commit_delay.unwrap_or(default.log_commit_delay),
+			log_reload: self.log_reload.unwrap_or(default.log_reload),
+			diff_word_diff: self.diff_word_diff.unwrap_or(default.diff_word_diff),
 		}
 	}
 }
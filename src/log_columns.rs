@@ -0,0 +1,325 @@
+use crate::args::get_app_config_path;
+use anyhow::{bail, Result};
+use ron::{
+	de::from_bytes,
+	ser::{to_string_pretty, PrettyConfig},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+	cell::RefCell,
+	fs::File,
+	io::{Read, Write},
+	path::{Path, PathBuf},
+	rc::Rc,
+};
+
+const LOG_COLUMNS_FILE: &str = "log_columns.ron";
+
+pub type SharedLogColumns = Rc<RefCell<LogColumns>>;
+
+/// optional columns in front of the commit message in the log
+#[derive(
+	Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub enum LogColumn {
+	Initials,
+	Signature,
+	Hash,
+	Time,
+	/// time between authoring and committing
+	CommitDelay,
+	Author,
+}
+
+impl LogColumn {
+	pub const ALL: [Self; 6] = [
+		Self::Initials,
+		Self::Signature,
+		Self::Hash,
+		Self::Time,
+		Self::CommitDelay,
+		Self::Author,
+	];
+}
+
+/// which log columns are shown in what order,
+/// along with the other options of how the log is shown
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct LogColumns {
+	/// every column exactly once (in display order) and if it is shown
+	columns: Vec<(LogColumn, bool)>,
+	/// long messages continue on a second line instead of being cut
+	#[serde(default)]
+	wrap_message: bool,
+	/// like `git log --oneline`: only the hash in front of the message
+	#[serde(default)]
+	oneline: bool,
+	/// show the full instead of the abbreviated commit hash
+	#[serde(default)]
+	full_hash: bool,
+	/// see `AsyncLog::set_topo_order`
+	#[serde(default)]
+	topo_order: bool,
+	/// see `AsyncLog::set_no_merges`
+	#[serde(default)]
+	no_merges: bool,
+}
+
+impl Default for LogColumns {
+	fn default() -> Self {
+		Self {
+			columns: vec![
+				(LogColumn::Initials, false),
+				(LogColumn::Signature, false),
+				(LogColumn::Hash, true),
+				(LogColumn::Time, true),
+				(LogColumn::CommitDelay, false),
+				(LogColumn::Author, true),
+			],
+			wrap_message: false,
+			oneline: false,
+			full_hash: false,
+			topo_order: false,
+			no_merges: false,
+		}
+	}
+}
+
+impl LogColumns {
+	/// defaults if there is no config file yet,
+	/// errors if the existing one is invalid
+	pub fn init() -> Result<Self> {
+		let file = Self::config_file()?;
+		if file.exists() {
+			Self::read_file(&file)
+		} else {
+			Ok(Self::default())
+		}
+	}
+
+	pub fn save(&self) -> Result<()> {
+		self.save_file(&Self::config_file()?)
+	}
+
+	/// all columns in display order and if they are shown
+	pub fn iter(
+		&self,
+	) -> impl Iterator<Item = (LogColumn, bool)> + '_ {
+		self.columns.iter().copied()
+	}
+
+	/// the shown columns in display order
+	pub fn visible(&self) -> Vec<LogColumn> {
+		if self.oneline {
+			return vec![LogColumn::Hash];
+		}
+
+		self.iter()
+			.filter(|(_, shown)| *shown)
+			.map(|(column, _)| column)
+			.collect()
+	}
+
+	pub fn is_visible(&self, column: LogColumn) -> bool {
+		self.visible().contains(&column)
+	}
+
+	pub fn toggle(&mut self, column: LogColumn) {
+		if let Some(entry) =
+			self.columns.iter_mut().find(|(c, _)| *c == column)
+		{
+			entry.1 = !entry.1;
+		}
+	}
+
+	/// see `wrap_message`
+	pub const fn wrap_message(&self) -> bool {
+		self.wrap_message
+	}
+
+	pub fn toggle_wrap_message(&mut self) {
+		self.wrap_message = !self.wrap_message;
+	}
+
+	/// the configured columns are kept and shown again
+	/// when turning it off
+	pub fn toggle_oneline(&mut self) {
+		self.oneline = !self.oneline;
+	}
+
+	/// see `full_hash`
+	pub const fn full_hash(&self) -> bool {
+		self.full_hash
+	}
+
+	pub fn toggle_full_hash(&mut self) {
+		self.full_hash = !self.full_hash;
+	}
+
+	/// see `topo_order`
+	pub const fn topo_order(&self) -> bool {
+		self.topo_order
+	}
+
+	pub fn toggle_topo_order(&mut self) {
+		self.topo_order = !self.topo_order;
+	}
+
+	/// see `no_merges`
+	pub const fn no_merges(&self) -> bool {
+		self.no_merges
+	}
+
+	pub fn toggle_no_merges(&mut self) {
+		self.no_merges = !self.no_merges;
+	}
+
+	/// moves the column at `idx` one position up (or down),
+	/// returns its new index
+	pub fn move_column(&mut self, idx: usize, up: bool) -> usize {
+		let target = if up {
+			idx.saturating_sub(1)
+		} else {
+			idx.saturating_add(1)
+		};
+
+		if idx < self.columns.len() && target < self.columns.len() {
+			self.columns.swap(idx, target);
+			target
+		} else {
+			idx
+		}
+	}
+
+	/// columns added after the file was written are appended hidden
+	fn add_missing(&mut self) {
+		for column in &LogColumn::ALL {
+			if !self.columns.iter().any(|(c, _)| c == column) {
+				self.columns.push((*column, false));
+			}
+		}
+	}
+
+	fn validate(&self) -> Result<()> {
+		for column in &LogColumn::ALL {
+			let count = self
+				.columns
+				.iter()
+				.filter(|(c, _)| c == column)
+				.count();
+			if count != 1 {
+				bail!(
+					"column {:?} has to be listed exactly once (found {})",
+					column,
+					count
+				);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn config_file() -> Result<PathBuf> {
+		Ok(get_app_config_path()?.join(LOG_COLUMNS_FILE))
+	}
+
+	fn save_file(&self, file: &Path) -> Result<()> {
+		let mut file = File::create(file)?;
+		let data = to_string_pretty(self, PrettyConfig::default())?;
+		file.write_all(data.as_bytes())?;
+		Ok(())
+	}
+
+	fn read_file(file: &Path) -> Result<Self> {
+		let mut f = File::open(file)?;
+		let mut buffer = Vec::new();
+		f.read_to_end(&mut buffer)?;
+		let mut columns: Self = from_bytes(&buffer)?;
+		columns.add_missing();
+		columns.validate()?;
+		Ok(columns)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{env, fs, process};
+
+	#[test]
+	fn test_roundtrip_and_invalid() {
+		let file = env::temp_dir()
+			.join(format!("gitui_log_columns_{}.ron", process::id()));
+
+		let mut columns = LogColumns::default();
+		columns.toggle(LogColumn::Initials);
+		columns.toggle_topo_order();
+		columns.toggle_no_merges();
+		assert_eq!(columns.move_column(0, false), 1);
+		columns.save_file(&file).unwrap();
+		assert_eq!(LogColumns::read_file(&file).unwrap(), columns);
+
+		fs::write(
+			&file,
+			"(columns: [(Hash, true), (Hash, false), (Time, true)])",
+		)
+		.unwrap();
+		assert!(LogColumns::read_file(&file).is_err());
+
+		fs::write(
+			&file,
+			"(columns: [(Author, true), (Hash, false)])",
+		)
+		.unwrap();
+		let read = LogColumns::read_file(&file).unwrap();
+		assert_eq!(read.visible(), vec![LogColumn::Author]);
+		assert_eq!(read.iter().count(), LogColumn::ALL.len());
+
+		// files from before `wrap_message` existed stay valid
+		columns.toggle_wrap_message();
+		columns.save_file(&file).unwrap();
+		let data = fs::read_to_string(&file)
+			.unwrap()
+			.replace("wrap_message: true,", "");
+		fs::write(&file, data).unwrap();
+		let read = LogColumns::read_file(&file).unwrap();
+		assert!(!read.wrap_message());
+		assert_eq!(read.visible(), columns.visible());
+
+		fs::remove_file(&file).unwrap();
+		assert!(LogColumns::read_file(&file).is_err());
+	}
+
+	#[test]
+	fn test_visible_in_order() {
+		let mut columns = LogColumns::default();
+		assert_eq!(
+			columns.visible(),
+			vec![LogColumn::Hash, LogColumn::Time, LogColumn::Author]
+		);
+
+		columns.toggle(LogColumn::Initials);
+		let idx = columns.move_column(0, false);
+		let idx = columns.move_column(idx, false);
+		assert_eq!(idx, 2);
+		assert_eq!(columns.move_column(5, false), 5);
+		assert_eq!(
+			columns.visible(),
+			vec![
+				LogColumn::Hash,
+				LogColumn::Initials,
+				LogColumn::Time,
+				LogColumn::Author
+			]
+		);
+		assert!(!columns.is_visible(LogColumn::Signature));
+
+		columns.toggle_oneline();
+		assert_eq!(columns.visible(), vec![LogColumn::Hash]);
+		assert!(!columns.is_visible(LogColumn::Initials));
+
+		columns.toggle_oneline();
+		assert_eq!(columns.visible().len(), 4);
+	}
+}
@@ -26,10 +26,13 @@ mod cmdbar;
 mod components;
 mod input;
 mod keys;
+mod log_columns;
 mod notify_mutex;
+mod pins;
 mod popup_stack;
 mod profiler;
 mod queue;
+mod session;
 mod spinner;
 mod string_utils;
 mod strings;
@@ -51,6 +54,7 @@ use crossterm::{
 };
 use input::{Input, InputEvent, InputState};
 use keys::KeyConfig;
+use log_columns::LogColumns;
 use profiler::Profiler;
 use scopeguard::defer;
 use scopetime::scope_time;
@@ -117,6 +121,14 @@ fn main() -> Result<()> {
 	let theme = Theme::init(cliargs.theme)
 		.map_err(|e| eprintln!("Theme loading error: {}", e))
 		.unwrap_or_default();
+	let log_columns = LogColumns::init()
+		.map_err(|e| {
+			eprintln!(
+				"Log columns loading error (using defaults): {}",
+				e
+			);
+		})
+		.unwrap_or_default();
 
 	setup_terminal()?;
 	defer! {
@@ -143,6 +155,7 @@ fn main() -> Result<()> {
 		input,
 		theme,
 		key_config,
+		log_columns,
 	);
 
 	let mut spinner = Spinner::default();
@@ -200,6 +213,7 @@ fn main() -> Result<()> {
 			spinner.draw(&mut terminal)?;
 
 			if app.is_quit() {
+				app.save_session();
 				break;
 			}
 		}
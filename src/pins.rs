@@ -0,0 +1,110 @@
+use anyhow::Result;
+use asyncgit::sync::{repo_dir, CommitId, RepoPath};
+use ron::{
+	de::from_bytes,
+	ser::{to_string_pretty, PrettyConfig},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+	fs::File,
+	io::{Read, Write},
+	path::{Path, PathBuf},
+};
+
+const PINS_FILE: &str = "gitui_pins.ron";
+
+/// commits pinned to the top of the log, stored per repository
+#[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pins {
+	/// hashes of the pinned commits in the order they were pinned
+	commits: Vec<String>,
+}
+
+impl Pins {
+	/// returns defaults if there is no (valid) pins file
+	pub fn load(repo: &RepoPath) -> Self {
+		pins_file(repo)
+			.and_then(|file| Self::read_file(&file))
+			.unwrap_or_default()
+	}
+
+	pub fn save(&self, repo: &RepoPath) -> Result<()> {
+		self.save_file(&pins_file(repo)?)
+	}
+
+	/// pinned commits, invalid hashes are skipped
+	pub fn ids(&self) -> Vec<CommitId> {
+		self.commits
+			.iter()
+			.filter_map(|id| CommitId::from_str_unchecked(id).ok())
+			.collect()
+	}
+
+	pub fn is_pinned(&self, id: CommitId) -> bool {
+		self.commits.contains(&id.to_string())
+	}
+
+	/// pins `id` or unpins it if it already was,
+	/// returns if it is pinned now
+	pub fn toggle(&mut self, id: CommitId) -> bool {
+		let id = id.to_string();
+		if self.commits.contains(&id) {
+			self.commits.retain(|other| *other != id);
+			false
+		} else {
+			self.commits.push(id);
+			true
+		}
+	}
+
+	fn save_file(&self, file: &Path) -> Result<()> {
+		let mut file = File::create(file)?;
+		let data = to_string_pretty(self, PrettyConfig::default())?;
+		file.write_all(data.as_bytes())?;
+		Ok(())
+	}
+
+	fn read_file(file: &Path) -> Result<Self> {
+		let mut f = File::open(file)?;
+		let mut buffer = Vec::new();
+		f.read_to_end(&mut buffer)?;
+		Ok(from_bytes(&buffer)?)
+	}
+}
+
+fn pins_file(repo: &RepoPath) -> Result<PathBuf> {
+	Ok(repo_dir(repo)?.join(PINS_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{env, fs, process};
+
+	#[test]
+	fn test_toggle_and_roundtrip() {
+		let file = env::temp_dir()
+			.join(format!("gitui_pins_{}.ron", process::id()));
+
+		let id1 =
+			CommitId::from_str_unchecked(&format!("{:040x}", 1))
+				.unwrap();
+		let id2 =
+			CommitId::from_str_unchecked(&format!("{:040x}", 2))
+				.unwrap();
+
+		let mut pins = Pins::default();
+		assert!(pins.toggle(id1));
+		assert!(pins.toggle(id2));
+		assert!(!pins.toggle(id1));
+		assert!(!pins.is_pinned(id1));
+		assert!(pins.is_pinned(id2));
+		assert_eq!(pins.ids(), vec![id2]);
+
+		pins.save_file(&file).unwrap();
+		assert_eq!(Pins::read_file(&file).unwrap(), pins);
+
+		fs::remove_file(&file).unwrap();
+		assert!(Pins::read_file(&file).is_err());
+	}
+}
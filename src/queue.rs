@@ -6,10 +6,11 @@ use crate::{
 	tabs::StashingOptions,
 };
 use asyncgit::{
-	sync::{diff::DiffLinePosition, CommitId, TreeFile},
+	sync::{diff::DiffLinePosition, CommitId},
 	PushType,
 };
 use bitflags::bitflags;
+use crossterm::event::KeyEvent;
 use std::{
 	cell::RefCell, collections::VecDeque, path::PathBuf, rc::Rc,
 };
@@ -52,6 +53,7 @@ pub enum Action {
 	AbortMerge,
 	AbortRebase,
 	AbortRevert,
+	RevertCommit(CommitId),
 }
 
 #[derive(Debug)]
@@ -84,6 +86,8 @@ pub enum InternalEvent {
 	StatusLastFileMoved,
 	/// open commit msg input
 	OpenCommit,
+	/// open commit msg input to amend the message of HEAD
+	OpenAmendCommit,
 	///
 	PopupStashing(StashingOptions),
 	///
@@ -93,6 +97,20 @@ pub enum InternalEvent {
 	///
 	TagCommit(CommitId),
 	///
+	CreateWorktree(CommitId),
+	/// edit the annotation of the tag named `String` on the commit
+	EditTag(CommitId, String, String),
+	/// a tag changed in place, reload the tags of the log
+	TagsChanged,
+	/// pick the remote commit links point to
+	SelectUrlRemote,
+	///
+	UrlRemoteSelected(String),
+	/// pick the branch to jump to the merge-base with
+	SelectMergeBaseBranch,
+	/// branch name and its top commit
+	GotoMergeBase(String, CommitId),
+	///
 	Tags,
 	///
 	CreateBranch,
@@ -111,7 +129,23 @@ pub enum InternalEvent {
 	///
 	OptionSwitched(AppOption),
 	///
-	OpenFileFinder(Vec<TreeFile>),
+	OpenLogColumns,
+	///
+	LogColumnsChanged,
+	///
+	OpenRecentCommits,
+	///
+	OpenGotoCommit,
+	/// 1-based position in the log
+	GotoCommitPosition(usize),
+	///
+	OpenLogCommandPalette,
+	/// run the log command bound to this key
+	RunLogCommand(KeyEvent),
+	/// preview squashing the range between the two commits
+	OpenSquashPreview((CommitId, CommitId)),
+	///
+	OpenFileFinder(Vec<PathBuf>),
 	///
 	FileFinderChanged(Option<PathBuf>),
 	///
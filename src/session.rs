@@ -0,0 +1,78 @@
+use anyhow::Result;
+use asyncgit::sync::{repo_dir, RepoPath};
+use ron::{
+	de::from_bytes,
+	ser::{to_string_pretty, PrettyConfig},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+	fs::File,
+	io::{Read, Write},
+	path::{Path, PathBuf},
+};
+
+const SESSION_FILE: &str = "gitui_session.ron";
+
+/// ui state of the last run, stored per repository
+#[derive(Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+	pub tab: usize,
+	/// hash of the commit selected in the log
+	pub selected_commit: Option<String>,
+}
+
+impl Session {
+	/// returns defaults if there is no (valid) session file
+	pub fn load(repo: &RepoPath) -> Self {
+		session_file(repo)
+			.and_then(|file| Self::read_file(&file))
+			.unwrap_or_default()
+	}
+
+	pub fn save(&self, repo: &RepoPath) -> Result<()> {
+		self.save_file(&session_file(repo)?)
+	}
+
+	fn save_file(&self, file: &Path) -> Result<()> {
+		let mut file = File::create(file)?;
+		let data = to_string_pretty(self, PrettyConfig::default())?;
+		file.write_all(data.as_bytes())?;
+		Ok(())
+	}
+
+	fn read_file(file: &Path) -> Result<Self> {
+		let mut f = File::open(file)?;
+		let mut buffer = Vec::new();
+		f.read_to_end(&mut buffer)?;
+		Ok(from_bytes(&buffer)?)
+	}
+}
+
+fn session_file(repo: &RepoPath) -> Result<PathBuf> {
+	Ok(repo_dir(repo)?.join(SESSION_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{env, fs, process};
+
+	#[test]
+	fn test_roundtrip_and_invalid() {
+		let file = env::temp_dir()
+			.join(format!("gitui_session_{}.ron", process::id()));
+
+		let session = Session {
+			tab: 1,
+			selected_commit: Some(String::from("abc")),
+		};
+		session.save_file(&file).unwrap();
+		assert_eq!(Session::read_file(&file).unwrap(), session);
+
+		fs::write(&file, "not a session").unwrap();
+		assert!(Session::read_file(&file).is_err());
+
+		fs::remove_file(&file).unwrap();
+		assert!(Session::read_file(&file).is_err());
+	}
+}
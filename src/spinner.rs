@@ -3,7 +3,7 @@ use tui::{backend::Backend, Terminal};
 
 // static SPINNER_CHARS: &[char] = &['◢', '◣', '◤', '◥'];
 // static SPINNER_CHARS: &[char] = &['⢹', '⢺', '⢼', '⣸', '⣇', '⡧', '⡗', '⡏'];
-static SPINNER_CHARS: &[char] =
+pub static SPINNER_CHARS: &[char] =
 	&['⣷', '⣯', '⣟', '⡿', '⢿', '⣻', '⣽', '⣾'];
 
 ///
@@ -1,6 +1,6 @@
-use asyncgit::sync::CommitId;
+use asyncgit::sync::{CommitId, CommitLineStats};
 
-use crate::keys::SharedKeyConfig;
+use crate::{keys::SharedKeyConfig, log_columns::LogColumn};
 
 pub mod order {
 	pub static NAV: i8 = 2;
@@ -26,11 +26,14 @@ pub static PUSH_TAGS_STATES_DONE: &str = "done";
 pub mod symbol {
 	pub const WHITESPACE: &str = "\u{00B7}"; //·
 	pub const CHECKMARK: &str = "\u{2713}"; //✓
+	pub const CROSSMARK: &str = "\u{2717}"; //✗
+	pub const QUESTION_MARK: &str = "?";
 	pub const SPACE: &str = "\u{02FD}"; //˽
 	pub const EMPTY_SPACE: &str = " ";
 	pub const FOLDER_ICON_COLLAPSED: &str = "\u{25b8}"; //▸
 	pub const FOLDER_ICON_EXPANDED: &str = "\u{25be}"; //▾
 	pub const EMPTY_STR: &str = "";
+	pub const ELLIPSIS: &str = "\u{2026}"; //…
 }
 
 pub fn title_branches() -> String {
@@ -103,6 +106,9 @@ pub fn commit_title_revert() -> String {
 pub fn commit_title_amend() -> String {
 	"Commit (Amend)".to_string()
 }
+pub fn commit_amend_draft_pending() -> String {
+	"cannot amend: the commit message draft is not empty".to_string()
+}
 pub fn commit_msg(_key_config: &SharedKeyConfig) -> String {
 	"type commit message..".to_string()
 }
@@ -163,6 +169,15 @@ pub fn confirm_msg_merge(
 pub fn confirm_title_abortmerge() -> String {
 	"Abort merge?".to_string()
 }
+pub fn confirm_title_revert_commit() -> String {
+	"Revert commit?".to_string()
+}
+pub fn confirm_msg_revert_commit(id: &CommitId) -> String {
+	format!(
+		"This reverts the changes of commit {} on top of the working tree. Are you sure?",
+		id.get_short_string()
+	)
+}
 pub fn confirm_title_abortrevert() -> String {
 	"Abort revert?".to_string()
 }
@@ -269,6 +284,135 @@ pub fn confirm_msg_force_push(
 pub fn log_title(_key_config: &SharedKeyConfig) -> String {
 	"Commit".to_string()
 }
+pub fn log_title_with_modes(
+	key_config: &SharedKeyConfig,
+	modes: &[String],
+) -> String {
+	if modes.is_empty() {
+		log_title(key_config)
+	} else {
+		format!("{} [{}]", log_title(key_config), modes.join(", "))
+	}
+}
+pub const fn log_no_tagged_commit() -> &'static str {
+	"no further tagged commit"
+}
+/// merges are reverted relative to their first parent
+pub fn revert_command(
+	hash: &str,
+	no_commit: bool,
+	merge: bool,
+) -> String {
+	format!(
+		"git revert{}{} {}",
+		if no_commit { " -n" } else { "" },
+		if merge { " -m 1" } else { "" },
+		hash
+	)
+}
+pub fn log_copied_author_hashes(
+	count: usize,
+	author: &str,
+) -> String {
+	format!("copied {} hashes of {}", count, author)
+}
+pub fn log_copied_range_subjects(count: usize) -> String {
+	format!("copied {} subjects", count)
+}
+pub fn log_finding_author_commits(author: &str) -> String {
+	format!("finding commits of {}..", author)
+}
+pub const fn log_no_author_commits() -> &'static str {
+	"no loaded commits of this author"
+}
+pub fn log_merge_base(branch: &str, hash: &str) -> String {
+	format!("merge-base with {}: {}", branch, hash)
+}
+pub fn log_no_merge_base(branch: &str) -> String {
+	format!("no common ancestor with {}", branch)
+}
+pub const fn log_pinned_title() -> &'static str {
+	"Pinned"
+}
+pub const fn log_more_below() -> &'static str {
+	"\u{2193} more"
+}
+pub fn log_load_progress(
+	loaded: usize,
+	bound: Option<usize>,
+) -> String {
+	bound.map_or_else(
+		|| format!("{} commits", loaded),
+		|bound| format!("{}/{} commits", loaded.min(bound), bound),
+	)
+}
+pub const fn log_mode_first_parent() -> &'static str {
+	"first-parent"
+}
+pub const fn log_mode_topo_order() -> &'static str {
+	"topo-order"
+}
+pub const fn log_mode_no_merges() -> &'static str {
+	"no-merges"
+}
+pub const fn log_mode_hide_empty() -> &'static str {
+	"no empty commits"
+}
+pub const fn log_mode_only_conflicted() -> &'static str {
+	"unresolved conflicts"
+}
+pub const fn log_mode_weekend() -> &'static str {
+	"weekend"
+}
+pub const fn log_mode_weekdays() -> &'static str {
+	"weekdays"
+}
+pub fn log_mode_max_commits(max_commits: usize) -> String {
+	format!("showing first {}", max_commits)
+}
+pub fn log_mode_since(date: &str) -> String {
+	format!("since {}", date)
+}
+pub const fn log_columns_title() -> &'static str {
+	"Log Columns"
+}
+pub fn squash_preview_title(count: usize) -> String {
+	format!("Squash Preview ({} commits)", count)
+}
+pub fn squash_preview_stats(stats: &CommitLineStats) -> String {
+	format!(
+		"{} files changed, +{} -{}",
+		stats.files, stats.added, stats.removed
+	)
+}
+pub const fn recent_commits_title() -> &'static str {
+	"Recently Inspected"
+}
+pub const fn recent_commits_empty() -> &'static str {
+	"no commits inspected yet"
+}
+pub const fn remote_list_title() -> &'static str {
+	"Remote for Links"
+}
+pub const fn merge_base_title() -> &'static str {
+	"Merge-Base with Branch"
+}
+pub const fn log_column_name(column: LogColumn) -> &'static str {
+	match column {
+		LogColumn::Initials => "Author initials",
+		LogColumn::Signature => "Signature",
+		LogColumn::Hash => "Hash",
+		LogColumn::Time => "Time",
+		LogColumn::CommitDelay => "Commit delay",
+		LogColumn::Author => "Author",
+	}
+}
+pub fn log_describe(description: &str) -> String {
+	format!("describe: {}", description)
+}
+pub const fn log_describe_no_tag() -> &'static str {
+	"describe: no tag reachable"
+}
 pub fn file_log_title(
 	file_path: &str,
 	selected: usize,
@@ -288,9 +432,40 @@ pub fn tag_popup_name_title() -> String {
 pub fn tag_popup_name_msg() -> String {
 	"type tag name".to_string()
 }
+pub const fn command_palette_title() -> &'static str {
+	"Log Commands"
+}
+pub fn file_log_not_in_commit(path: &str, id: CommitId) -> String {
+	format!(
+		"'{}' is not changed by commit {}",
+		path,
+		id.get_short_string()
+	)
+}
+pub fn goto_commit_popup_title() -> String {
+	"Go to Commit".to_string()
+}
+pub fn goto_commit_popup_msg() -> String {
+	"position in the log (1 is the newest)".to_string()
+}
+pub fn goto_commit_invalid(input: &str) -> String {
+	format!("'{}' is not a valid position in the log", input)
+}
+pub fn create_worktree_popup_title() -> String {
+	"Create Worktree".to_string()
+}
+pub fn create_worktree_popup_msg() -> String {
+	"directory of the new worktree (relative to the repo)".to_string()
+}
+pub fn create_worktree_success(path: &str) -> String {
+	format!("created worktree at '{}'", path)
+}
 pub fn tag_popup_annotation_title(name: &str) -> String {
 	format!("Tag Annotation ({})", name)
 }
+pub fn edit_tag_popup_title(name: &str) -> String {
+	format!("Edit Tag Annotation ({})", name)
+}
 pub fn tag_popup_annotation_msg() -> String {
 	"type tag annotation".to_string()
 }
@@ -347,6 +522,7 @@ pub fn rename_branch_popup_msg(
 
 pub mod commit {
 	use crate::keys::SharedKeyConfig;
+	use asyncgit::sync::DiffParent;
 
 	pub fn details_author() -> String {
 		"Author: ".to_string()
@@ -363,9 +539,32 @@ pub mod commit {
 	pub fn details_tags() -> String {
 		"Tags: ".to_string()
 	}
+	pub fn details_parents() -> String {
+		"Parents: ".to_string()
+	}
+	pub fn details_no_parents() -> String {
+		"(none)".to_string()
+	}
+	pub fn details_diff_parent(parent: DiffParent) -> String {
+		match parent {
+			DiffParent::Nth(0) => "[diff: first parent]".to_string(),
+			DiffParent::Nth(1) => "[diff: second parent]".to_string(),
+			DiffParent::Nth(idx) => {
+				format!("[diff: parent {}]", idx + 1)
+			}
+			DiffParent::Combined => "[diff: combined]".to_string(),
+		}
+	}
 	pub fn details_message() -> String {
 		"Subject: ".to_string()
 	}
+	pub fn details_files_changed(files: usize) -> String {
+		format!(
+			"{} file{}, ",
+			files,
+			if files == 1 { "" } else { "s" }
+		)
+	}
 	pub fn details_info_title(
 		_key_config: &SharedKeyConfig,
 	) -> String {
@@ -382,154 +581,691 @@ pub mod commit {
 	) -> String {
 		"Message".to_string()
 	}
-	pub fn details_files_title(
-		_key_config: &SharedKeyConfig,
-	) -> String {
-		"Files:".to_string()
+	pub fn details_files_title(
+		_key_config: &SharedKeyConfig,
+	) -> String {
+		"Files:".to_string()
+	}
+}
+
+pub mod commands {
+	use crate::components::CommandText;
+	use crate::keys::SharedKeyConfig;
+
+	static CMD_GROUP_GENERAL: &str = "-- General --";
+	static CMD_GROUP_DIFF: &str = "-- Diff --";
+	static CMD_GROUP_CHANGES: &str = "-- Changes --";
+	static CMD_GROUP_COMMIT: &str = "-- Commit --";
+	static CMD_GROUP_STASHING: &str = "-- Stashing --";
+	static CMD_GROUP_STASHES: &str = "-- Stashes --";
+	static CMD_GROUP_LOG: &str = "-- Log --";
+	static CMD_GROUP_BRANCHES: &str = "-- Branches --";
+
+	pub fn toggle_tabs(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Next [{}]",
+				key_config.get_hint(key_config.keys.tab_toggle)
+			),
+			"switch to next tab",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn find_file(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Find [{}]",
+				key_config.get_hint(key_config.keys.file_find)
+			),
+			"find file in tree",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn find_file_in_list(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Jump to file [{}]",
+				key_config.get_hint(key_config.keys.file_list_find)
+			),
+			"fuzzy find a file in the list and select it",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn toggle_tabs_direct(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Tab [{}{}{}{}{}]",
+				key_config.get_hint(key_config.keys.tab_status),
+				key_config.get_hint(key_config.keys.tab_log),
+				key_config.get_hint(key_config.keys.tab_files),
+				key_config.get_hint(key_config.keys.tab_stashing),
+				key_config.get_hint(key_config.keys.tab_stashes),
+			),
+			"switch top level tabs directly",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn options_popup(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Options [{}]",
+				key_config.get_hint(key_config.keys.open_options),
+			),
+			"open options popup",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn help_open(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Help [{}]",
+				key_config.get_hint(key_config.keys.open_help)
+			),
+			"open this help screen",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn navigate_commit_message(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Nav [{}{}]",
+				key_config.get_hint(key_config.keys.move_up),
+				key_config.get_hint(key_config.keys.move_down)
+			),
+			"navigate commit message",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn inspect_parent(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Inspect Parent [{}{}]",
+				key_config.get_hint(key_config.keys.select_parent),
+				key_config.get_hint(key_config.keys.inspect_parent)
+			),
+			"select a parent and inspect it",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn toggle_commit_ansi(
+		key_config: &SharedKeyConfig,
+		interpret: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} ANSI [{}]",
+				if interpret { "Strip" } else { "Color" },
+				key_config.get_hint(key_config.keys.toggle_ansi),
+			),
+			"strip or interpret ANSI escape sequences in the message",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn diff_parent(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Diff Parent [{}]",
+				key_config.get_hint(key_config.keys.diff_parent),
+			),
+			"list the changes of a merge against another parent or all of them",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn navigate_tree(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Nav [{}{}{}{}]",
+				key_config.get_hint(key_config.keys.move_up),
+				key_config.get_hint(key_config.keys.move_down),
+				key_config.get_hint(key_config.keys.move_right),
+				key_config.get_hint(key_config.keys.move_left)
+			),
+			"navigate tree view, collapse, expand",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn scroll(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Scroll [{}{}]",
+				key_config.get_hint(key_config.keys.focus_above),
+				key_config.get_hint(key_config.keys.focus_below)
+			),
+			"scroll up or down in focused view",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn commit_list_mark(
+		key_config: &SharedKeyConfig,
+		marked: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} [{}]",
+				if marked { "Unmark" } else { "Mark" },
+				key_config.get_hint(key_config.keys.log_mark_commit),
+			),
+			"mark multiple commits",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn copy(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy [{}]",
+				key_config.get_hint(key_config.keys.copy),
+			),
+			"copy selected lines to clipboard",
+			CMD_GROUP_DIFF,
+		)
+	}
+	pub fn copy_hash(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Short Hash [{}]",
+				key_config.get_hint(key_config.keys.copy),
+			),
+			"copy selected (or all marked) short commit hashes to clipboard",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn copy_full_hash(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Full Hash [{}]",
+				key_config.get_hint(key_config.keys.log_copy_full_hash),
+			),
+			"copy selected (or all marked) full 40 character commit hashes to clipboard",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn copy_tree_hash(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Tree Hash [{}]",
+				key_config
+					.get_hint(key_config.keys.log_copy_tree_hash),
+			),
+			"copy tree hash of selected commit to clipboard",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn copy_markdown_link(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Link [{}]",
+				key_config
+					.get_hint(key_config.keys.log_copy_markdown_link),
+			),
+			"copy selected commit as markdown link to clipboard",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn copy_commit_date(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Date [{}]",
+				key_config.get_hint(key_config.keys.log_copy_date),
+			),
+			"copy full date of selected commit to clipboard",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_goto_merge_base(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Merge-Base [{}]",
+				key_config.get_hint(key_config.keys.log_merge_base),
+			),
+			"select the merge-base of HEAD and a branch",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn copy_revert_command(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Revert Cmd [{}]",
+				key_config
+					.get_hint(key_config.keys.log_copy_revert_command),
+			),
+			"copy the git command reverting the selected commit (gitui.revertCommandNoCommit)",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn copy_author_hashes(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Author Hashes [{}]",
+				key_config.get_hint(key_config.keys.log_copy_author_hashes),
+			),
+			"copy hashes of all loaded commits by the selected commit's author",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_max_commits(
+		key_config: &SharedKeyConfig,
+		limited: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} [{}]",
+				if limited { "Load All" } else { "Limit Commits" },
+				key_config.get_hint(key_config.keys.log_max_commits),
+			),
+			"switch between loading all commits and the limit set in gitui.logMaxCommits (only configurable there)",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_inline_tags(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Inline Tags [{}]",
+				key_config.get_hint(key_config.keys.log_inline_tags),
+			),
+			"toggle tag names behind the commit message",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_emoji(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Emoji [{}]",
+				key_config.get_hint(key_config.keys.log_emoji),
+			),
+			"toggle rendering emoji shortcodes in commit messages",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_wrap_message(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Wrap Message [{}]",
+				key_config.get_hint(key_config.keys.log_wrap_message),
+			),
+			"toggle continuing long commit messages on a second line",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_oneline(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Oneline [{}]",
+				key_config.get_hint(key_config.keys.log_oneline),
+			),
+			"toggle showing only hash and message of each commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_full_hash(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Full Hash [{}]",
+				key_config.get_hint(key_config.keys.log_full_hash),
+			),
+			"toggle showing full instead of abbreviated commit hashes",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_topo_order(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Topo Order [{}]",
+				key_config.get_hint(key_config.keys.log_topo_order),
+			),
+			"toggle never listing a commit before its children instead of ordering by date",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_no_merges(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"No Merges [{}]",
+				key_config.get_hint(key_config.keys.log_no_merges),
+			),
+			"toggle leaving out merge commits",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_next_tagged(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Older Tag [{}]",
+				key_config.get_hint(key_config.keys.log_next_tagged),
+			),
+			"select the next older tagged commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_prev_tagged(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Newer Tag [{}]",
+				key_config.get_hint(key_config.keys.log_prev_tagged),
+			),
+			"select the next newer tagged commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_goto_parent(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Parent [{}]",
+				key_config.get_hint(key_config.keys.log_goto_parent),
+			),
+			"select first parent of selected commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_goto_child(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Child [{}]",
+				key_config.get_hint(key_config.keys.log_goto_child),
+			),
+			"select closest loaded child of selected commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_author_rank(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Author Rank [{}]",
+				key_config.get_hint(key_config.keys.log_author_rank),
+			),
+			"toggle showing the nth commit of each author in the loaded log",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_columns_popup(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Columns [{}]",
+				key_config.get_hint(key_config.keys.log_columns),
+			),
+			"choose which columns the log shows in what order",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_recent_commits(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Recent [{}]",
+				key_config
+					.get_hint(key_config.keys.log_recent_commits),
+			),
+			"list recently inspected commits",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_squash_preview(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Squash Preview [{}]",
+				key_config.get_hint(key_config.keys.log_squash_preview),
+			),
+			"preview squashing the range between the two marked commits",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_copy_range_subjects(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Copy Subjects [{}]",
+				key_config
+					.get_hint(key_config.keys.log_copy_range_subjects),
+			),
+			"copy the subjects of the range between the two marked commits, e.g. for release notes",
+			CMD_GROUP_LOG,
+		)
 	}
-}
-
-pub mod commands {
-	use crate::components::CommandText;
-	use crate::keys::SharedKeyConfig;
-
-	static CMD_GROUP_GENERAL: &str = "-- General --";
-	static CMD_GROUP_DIFF: &str = "-- Diff --";
-	static CMD_GROUP_CHANGES: &str = "-- Changes --";
-	static CMD_GROUP_COMMIT: &str = "-- Commit --";
-	static CMD_GROUP_STASHING: &str = "-- Stashing --";
-	static CMD_GROUP_STASHES: &str = "-- Stashes --";
-	static CMD_GROUP_LOG: &str = "-- Log --";
-	static CMD_GROUP_BRANCHES: &str = "-- Branches --";
-
-	pub fn toggle_tabs(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn recent_commits_select(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Next [{}]",
-				key_config.get_hint(key_config.keys.tab_toggle)
+				"Inspect [{}]",
+				key_config.get_hint(key_config.keys.enter),
 			),
-			"switch to next tab",
+			"select the commit in the log and inspect it",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn find_file(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn remote_list_select(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Find [{}]",
-				key_config.get_hint(key_config.keys.file_find)
+				"Select [{}]",
+				key_config.get_hint(key_config.keys.enter),
 			),
-			"find file in tree",
+			"link commits to the selected remote",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn toggle_tabs_direct(
+	pub fn merge_base_select(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Tab [{}{}{}{}{}]",
-				key_config.get_hint(key_config.keys.tab_status),
-				key_config.get_hint(key_config.keys.tab_log),
-				key_config.get_hint(key_config.keys.tab_files),
-				key_config.get_hint(key_config.keys.tab_stashing),
-				key_config.get_hint(key_config.keys.tab_stashes),
+				"Select [{}]",
+				key_config.get_hint(key_config.keys.enter),
 			),
-			"switch top level tabs directly",
+			"jump to the merge-base with the selected branch",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn options_popup(
+	pub fn log_columns_toggle(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Options [{}]",
-				key_config.get_hint(key_config.keys.open_options),
+				"Show/Hide [{}]",
+				key_config.get_hint(key_config.keys.enter),
 			),
-			"open options popup",
+			"show or hide the selected column",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn help_open(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn log_columns_move(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Help [{}]",
-				key_config.get_hint(key_config.keys.open_help)
+				"Move [{}{}]",
+				key_config.get_hint(key_config.keys.shift_up),
+				key_config.get_hint(key_config.keys.shift_down),
 			),
-			"open this help screen",
+			"move the selected column",
 			CMD_GROUP_GENERAL,
 		)
 	}
-	pub fn navigate_commit_message(
+	pub fn log_describe(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Describe [{}]",
+				key_config.get_hint(key_config.keys.log_describe),
+			),
+			"select the nearest tag of the selected commit (git describe)",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_author_initials(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Nav [{}{}]",
-				key_config.get_hint(key_config.keys.move_up),
-				key_config.get_hint(key_config.keys.move_down)
+				"Initials [{}]",
+				key_config.get_hint(key_config.keys.log_author_initials),
 			),
-			"navigate commit message",
-			CMD_GROUP_GENERAL,
+			"toggle colored author initials at the start of each commit",
+			CMD_GROUP_LOG,
 		)
 	}
-	pub fn navigate_tree(
+	pub fn log_toggle_commit_delay(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Nav [{}{}{}{}]",
-				key_config.get_hint(key_config.keys.move_up),
-				key_config.get_hint(key_config.keys.move_down),
-				key_config.get_hint(key_config.keys.move_right),
-				key_config.get_hint(key_config.keys.move_left)
+				"Commit Delay [{}]",
+				key_config.get_hint(key_config.keys.log_commit_delay),
 			),
-			"navigate tree view, collapse, expand",
-			CMD_GROUP_GENERAL,
+			"toggle the time between authoring and committing each commit",
+			CMD_GROUP_LOG,
 		)
 	}
-	pub fn scroll(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn log_toggle_signatures(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Scroll [{}{}]",
-				key_config.get_hint(key_config.keys.focus_above),
-				key_config.get_hint(key_config.keys.focus_below)
+				"Signatures [{}]",
+				key_config.get_hint(key_config.keys.log_signatures),
 			),
-			"scroll up or down in focused view",
-			CMD_GROUP_GENERAL,
+			"toggle marking signed commits (signatures are not verified)",
+			CMD_GROUP_LOG,
 		)
 	}
-	pub fn commit_list_mark(
+	pub fn log_reload(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Reload [{}]",
+				key_config.get_hint(key_config.keys.log_reload),
+			),
+			"reload log, tags and branch from scratch",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_since(
 		key_config: &SharedKeyConfig,
-		marked: bool,
+		active: bool,
 	) -> CommandText {
 		CommandText::new(
 			format!(
 				"{} [{}]",
-				if marked { "Unmark" } else { "Mark" },
-				key_config.get_hint(key_config.keys.log_mark_commit),
+				if active { "Load All" } else { "Recent Only" },
+				key_config.get_hint(key_config.keys.log_since),
 			),
-			"mark multiple commits",
-			CMD_GROUP_GENERAL,
+			"toggle loading only commits of the last days (gitui.logSinceDays)",
+			CMD_GROUP_LOG,
 		)
 	}
-	pub fn copy(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn log_toggle_first_parent(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Copy [{}]",
-				key_config.get_hint(key_config.keys.copy),
+				"First Parent [{}]",
+				key_config.get_hint(key_config.keys.log_first_parent),
 			),
-			"copy selected lines to clipboard",
-			CMD_GROUP_DIFF,
+			"toggle following only the first parent of merges",
+			CMD_GROUP_LOG,
 		)
 	}
-	pub fn copy_hash(key_config: &SharedKeyConfig) -> CommandText {
+	pub fn log_toggle_hide_empty(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
 		CommandText::new(
 			format!(
-				"Copy Hash [{}]",
-				key_config.get_hint(key_config.keys.copy),
+				"Hide Empty [{}]",
+				key_config.get_hint(key_config.keys.log_hide_empty),
+			),
+			"toggle hiding commits that change nothing",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_pin(
+		key_config: &SharedKeyConfig,
+		pinned: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} [{}]",
+				if pinned { "Unpin" } else { "Pin" },
+				key_config.get_hint(key_config.keys.log_pin_commit),
 			),
-			"copy selected commit hash to clipboard",
+			"pin commit to the top of the log or unpin it",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_only_conflicted(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Conflicted [{}]",
+				key_config.get_hint(key_config.keys.log_only_conflicted),
+			),
+			"toggle showing only merges that committed conflict markers (heuristic: misses octopus merges, custom marker sizes and conflicts a re-merge does not reproduce)",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_weekend(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Weekend [{}]",
+				key_config.get_hint(key_config.keys.log_weekend),
+			),
+			"cycle showing only weekend commits, only weekday commits or all (local time)",
 			CMD_GROUP_LOG,
 		)
 	}
@@ -620,6 +1356,20 @@ pub mod commands {
 			CMD_GROUP_DIFF,
 		)
 	}
+	pub fn diff_toggle_word_diff(
+		key_config: &SharedKeyConfig,
+		word_diff: bool,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"{} [{}]",
+				if word_diff { "Line Diff" } else { "Word Diff" },
+				key_config.get_hint(key_config.keys.diff_word_diff),
+			),
+			"toggle highlighting changed words within lines",
+			CMD_GROUP_DIFF,
+		)
+	}
 	pub fn diff_lines_unstage(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
@@ -802,6 +1552,18 @@ pub mod commands {
 			CMD_GROUP_COMMIT,
 		)
 	}
+	pub fn log_amend_message(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Amend Message [{}]",
+				key_config.get_hint(key_config.keys.commit_amend),
+			),
+			"amend message of selected HEAD commit (clean worktree only)",
+			CMD_GROUP_LOG,
+		)
+	}
 	pub fn edit_item(key_config: &SharedKeyConfig) -> CommandText {
 		CommandText::new(
 			format!(
@@ -1041,6 +1803,39 @@ pub mod commands {
 		)
 	}
 
+	pub fn log_details_focus(
+		key_config: &SharedKeyConfig,
+		details_focused: bool,
+	) -> CommandText {
+		CommandText::new(
+			if details_focused {
+				format!(
+					"Focus Log [{}]",
+					key_config.get_hint(key_config.keys.focus_left),
+				)
+			} else {
+				format!(
+					"Focus Details [{}]",
+					key_config.get_hint(key_config.keys.focus_right),
+				)
+			},
+			"move focus between log and commit details",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_toggle_details_focus(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Toggle Focus [{}]",
+				key_config
+					.get_hint(key_config.keys.log_toggle_details_focus),
+			),
+			"switch between the log and the file list of the inline details, showing them if hidden",
+			CMD_GROUP_LOG,
+		)
+	}
 	pub fn commit_details_open(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
@@ -1076,6 +1871,18 @@ pub mod commands {
 			CMD_GROUP_LOG,
 		)
 	}
+	pub fn toggle_blame_heatmap(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Age Heatmap [{}]",
+				key_config.get_hint(key_config.keys.blame_heatmap),
+			),
+			"color lines by age relative to the blamed commit",
+			CMD_GROUP_LOG,
+		)
+	}
 	pub fn log_tag_commit(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
@@ -1113,6 +1920,103 @@ pub mod commands {
 			CMD_GROUP_LOG,
 		)
 	}
+	pub fn log_command_palette(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Commands [{}]",
+				key_config
+					.get_hint(key_config.keys.log_command_palette),
+			),
+			"search and run the commands of the log",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn command_palette_run(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Run [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"run the selected command",
+			CMD_GROUP_GENERAL,
+		)
+	}
+	pub fn create_worktree_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Create [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"create the worktree at this directory",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn edit_tag_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Save [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"replace the tag annotation",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_edit_tag(key_config: &SharedKeyConfig) -> CommandText {
+		CommandText::new(
+			format!(
+				"Edit Tag [{}]",
+				key_config.get_hint(key_config.keys.log_edit_tag),
+			),
+			"edit the annotation of the selected commit's annotated tag",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_create_worktree(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Worktree [{}]",
+				key_config
+					.get_hint(key_config.keys.log_create_worktree),
+			),
+			"create a worktree checked out at the selected commit",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn goto_commit_confirm(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Go [{}]",
+				key_config.get_hint(key_config.keys.enter),
+			),
+			"select the commit at this position",
+			CMD_GROUP_LOG,
+		)
+	}
+	pub fn log_goto_position(
+		key_config: &SharedKeyConfig,
+	) -> CommandText {
+		CommandText::new(
+			format!(
+				"Go to Nth [{}]",
+				key_config
+					.get_hint(key_config.keys.log_goto_position),
+			),
+			"select the commit at a position in the log",
+			CMD_GROUP_LOG,
+		)
+	}
 	pub fn tag_commit_confirm_msg(
 		key_config: &SharedKeyConfig,
 	) -> CommandText {
@@ -1,26 +1,33 @@
 use crate::{
 	components::{
-		visibility_blocking, CommandBlocking, CommandInfo,
-		CommitDetailsComponent, CommitList, Component,
-		DrawableComponent, EventState, FileTreeOpen,
-		InspectCommitOpen,
+		is_weekend, time_to_string, visibility_blocking,
+		CommandBlocking, CommandInfo, CommitDetailsComponent,
+		CommitList, Component, DrawableComponent, EventState,
+		FileTreeOpen, InspectCommitOpen,
 	},
 	keys::SharedKeyConfig,
-	queue::{InternalEvent, Queue, StackablePopupOpen},
+	log_columns::SharedLogColumns,
+	pins::Pins,
+	queue::{Action, InternalEvent, Queue, StackablePopupOpen},
 	strings, try_or_popup,
 	ui::style::SharedTheme,
 };
 use anyhow::Result;
 use asyncgit::{
+	asyncjob::AsyncSingleJob,
+	author_commits::AsyncAuthorCommitsJob,
 	cached,
-	sync::{self, CommitId, RepoPathRef},
+	sync::{
+		self, status::StatusType, CommitId, LogWalkerFilter,
+		RepoPathRef,
+	},
 	AsyncGitNotification, AsyncLog, AsyncTags, CommitFilesParams,
-	FetchStatus,
+	FetchStatus, LogSleep,
 };
 use crossbeam_channel::Sender;
 use crossterm::event::Event;
-use std::time::Duration;
-use sync::CommitTags;
+use std::{path::PathBuf, str::FromStr, sync::Arc, time::Duration};
+use sync::{CommitTags, Tag};
 use tui::{
 	backend::Backend,
 	layout::{Constraint, Direction, Layout, Rect},
@@ -28,30 +35,139 @@ use tui::{
 };
 
 const SLICE_SIZE: usize = 1200;
+const MAX_COMMITS_CONFIG: &str = "gitui.logMaxCommits";
+const SINCE_DAYS_CONFIG: &str = "gitui.logSinceDays";
+const MESSAGE_MAX_LENGTH_CONFIG: &str = "gitui.logMessageMaxLength";
+const SINCE_DAYS_DEFAULT: i64 = 7;
+const TAG_WRAP_CONFIG: &str = "gitui.logTagNavigationWrap";
+const URL_REMOTE_CONFIG: &str = "gitui.urlRemote";
+const REVERT_NO_COMMIT_CONFIG: &str = "gitui.revertCommandNoCommit";
+/// reverting touches the working tree and index, so it asks
+/// first unless this is set to `false`
+const CONFIRM_REVERT_CONFIG: &str = "gitui.logConfirmRevert";
+/// e.g. `https://git.corp/{repo}/commit/{hash}` for forges
+/// the remote url cannot be turned into commit links for
+const COMMIT_URL_TEMPLATE_CONFIG: &str = "gitui.commitUrlTemplate";
+const SUBJECTS_OLDEST_FIRST_CONFIG: &str =
+	"gitui.logRangeSubjectsOldestFirst";
+const SLEEP_CONFIG: &str = "gitui.logSleepMs";
+const SLEEP_BACKGROUND_CONFIG: &str = "gitui.logBackgroundSleepMs";
 
 ///
+#[allow(clippy::struct_excessive_bools)]
 pub struct Revlog {
 	repo: RepoPathRef,
 	commit_details: CommitDetailsComponent,
 	list: CommitList,
 	git_log: AsyncLog,
 	git_tags: AsyncTags,
+	/// see `copy_author_hashes`
+	async_author_commits: AsyncSingleJob<AsyncAuthorCommitsJob>,
 	queue: Queue,
 	visible: bool,
 	branch_name: cached::BranchName,
+	/// the upstream may have moved, see `update_branch`
+	branch_outdated: bool,
+	pending_selection: Option<CommitId>,
+	/// configured limit of commits to load
+	max_commits: Option<usize>,
+	/// how many days back the recent-only mode loads
+	since_days: i64,
+	hide_empty: bool,
+	only_conflicted: bool,
+	/// kept across toggles so its cache is too
+	conflicted_filter: LogWalkerFilter,
+	/// `Some(true)` only shows weekend commits,
+	/// `Some(false)` only weekday ones
+	weekend: Option<bool>,
+	/// jumping past the last tagged commit continues at the first
+	tag_wrap: bool,
+	/// commits shown above the list, see `toggle_pin`
+	pins: Pins,
+	/// size of the last complete load, the expected size of the next
+	last_total: Option<usize>,
+	/// remote commit links point to, configured or picked
+	/// (defaults to `origin`)
+	url_remote: Option<String>,
+	/// copied revert commands only stage the revert (`-n`)
+	revert_no_commit: bool,
+	/// ask before reverting the selected commit
+	confirm_revert: bool,
+	/// order of the subjects copied for a range
+	subjects_oldest_first: bool,
+	/// overrides the commit urls derived from the remote
+	commit_url_template: Option<String>,
+	/// shared with `list`, also holds the options of the walk
+	columns: SharedLogColumns,
 	key_config: SharedKeyConfig,
 }
 
+/// git config `key` parsed as `T`,
+/// `None` if it is not set or does not parse
+fn config_value<T: FromStr>(
+	repo: &RepoPathRef,
+	key: &str,
+) -> Option<T> {
+	sync::get_config_string(&repo.borrow(), key)
+		.ok()
+		.flatten()
+		.and_then(|value| value.parse().ok())
+}
+
 impl Revlog {
 	///
+	#[allow(clippy::too_many_lines)]
 	pub fn new(
 		repo: &RepoPathRef,
 		queue: &Queue,
 		sender: &Sender<AsyncGitNotification>,
 		theme: SharedTheme,
 		key_config: SharedKeyConfig,
+		columns: SharedLogColumns,
 	) -> Self {
-		Self {
+		let max_commits =
+			config_value::<usize>(repo, MAX_COMMITS_CONFIG)
+				.filter(|max| *max > 0);
+		let since_days = config_value::<i64>(repo, SINCE_DAYS_CONFIG)
+			.filter(|days| *days > 0)
+			.unwrap_or(SINCE_DAYS_DEFAULT);
+		let message_max_length =
+			config_value::<usize>(repo, MESSAGE_MAX_LENGTH_CONFIG)
+				.filter(|max| *max > 0);
+		let tag_wrap =
+			config_value(repo, TAG_WRAP_CONFIG).unwrap_or_default();
+		let revert_no_commit =
+			config_value(repo, REVERT_NO_COMMIT_CONFIG)
+				.unwrap_or_default();
+		let confirm_revert =
+			config_value(repo, CONFIRM_REVERT_CONFIG).unwrap_or(true);
+		let commit_url_template =
+			config_value(repo, COMMIT_URL_TEMPLATE_CONFIG);
+		let subjects_oldest_first =
+			config_value(repo, SUBJECTS_OLDEST_FIRST_CONFIG)
+				.unwrap_or_default();
+		let url_remote =
+			config_value::<String>(repo, URL_REMOTE_CONFIG)
+				.filter(|remote| !remote.is_empty());
+
+		let mut git_log =
+			AsyncLog::new(repo.borrow().clone(), sender, None);
+		git_log.set_sleep(Self::log_sleep(repo));
+		if let Err(e) = git_log.set_max_commits(max_commits) {
+			log::error!("failed to limit log: {}", e);
+		}
+		if let Err(e) =
+			git_log.set_topo_order(columns.borrow().topo_order())
+		{
+			log::error!("failed to restore log order: {}", e);
+		}
+		if let Err(e) =
+			git_log.set_no_merges(columns.borrow().no_merges())
+		{
+			log::error!("failed to restore hiding merges: {}", e);
+		}
+
+		let mut revlog = Self {
 			repo: repo.clone(),
 			queue: queue.clone(),
 			commit_details: CommitDetailsComponent::new(
@@ -65,16 +181,59 @@ impl Revlog {
 				&strings::log_title(&key_config),
 				theme,
 				key_config.clone(),
+				columns.clone(),
 			),
-			git_log: AsyncLog::new(
-				repo.borrow().clone(),
-				sender,
-				None,
-			),
+			git_log,
 			git_tags: AsyncTags::new(repo.borrow().clone(), sender),
+			async_author_commits: AsyncSingleJob::new(sender.clone()),
 			visible: false,
 			branch_name: cached::BranchName::new(repo.clone()),
+			branch_outdated: true,
+			pending_selection: None,
+			max_commits,
+			since_days,
+			hide_empty: false,
+			only_conflicted: false,
+			conflicted_filter: sync::unresolved_conflict_merges(),
+			weekend: None,
+			tag_wrap,
+			pins: Pins::load(&repo.borrow()),
+			url_remote,
+			revert_no_commit,
+			confirm_revert,
+			subjects_oldest_first,
+			commit_url_template,
+			last_total: None,
+			columns,
 			key_config,
+		};
+
+		revlog.list.set_message_max_length(message_max_length);
+		revlog.update_title();
+
+		revlog
+	}
+
+	/// pause between loaded batches of commits, defaults unless
+	/// configured via `gitui.logSleepMs`/`gitui.logBackgroundSleepMs`
+	fn log_sleep(repo: &RepoPathRef) -> LogSleep {
+		let config_ms = |key: &str| {
+			config_value(repo, key).map(Duration::from_millis)
+		};
+
+		let default = LogSleep::default();
+		LogSleep {
+			foreground: config_ms(SLEEP_CONFIG)
+				.unwrap_or(default.foreground),
+			background: config_ms(SLEEP_BACKGROUND_CONFIG)
+				.unwrap_or(default.background),
+		}
+	}
+
+	///
+	pub fn file_finder_update(&mut self, file: &Option<PathBuf>) {
+		if self.visible {
+			self.commit_details.file_finder_update(file);
 		}
 	}
 
@@ -82,6 +241,7 @@ impl Revlog {
 	pub fn any_work_pending(&self) -> bool {
 		self.git_log.is_pending()
 			|| self.git_tags.is_pending()
+			|| self.async_author_commits.is_pending()
 			|| self.commit_details.any_work_pending()
 	}
 
@@ -91,7 +251,16 @@ impl Revlog {
 			let log_changed =
 				self.git_log.fetch()? == FetchStatus::Started;
 
-			self.list.set_count_total(self.git_log.count()?);
+			let status = self.git_log.status()?;
+			self.list.set_count_total(status.loaded);
+			self.list.set_pending(status.total().is_none());
+			if let Some(total) = status.total() {
+				self.last_total = Some(total);
+			}
+			self.list
+				.set_load_bound(self.last_total.or(self.max_commits));
+
+			self.select_pending()?;
 
 			let selection = self.list.selection();
 			let selection_max = self.list.selection_max();
@@ -103,9 +272,14 @@ impl Revlog {
 
 			self.git_tags.request(Duration::from_secs(3), false)?;
 
-			self.list.set_branch(
-				self.branch_name.lookup().map(Some).unwrap_or(None),
-			);
+			// a new walk starts whenever HEAD moves
+			let branch_name = self.branch_name.lookup().ok();
+			if self.branch_outdated
+				|| log_changed
+				|| branch_name.as_deref() != self.list.branch()
+			{
+				self.update_branch(branch_name);
+			}
 
 			if self.commit_details.is_visible() {
 				let commit = self.selected_commit();
@@ -121,15 +295,52 @@ impl Revlog {
 		Ok(())
 	}
 
+	/// compares the branch with its upstream, which walks the
+	/// history, so only when HEAD, the branch or the upstream moved
+	fn update_branch(&mut self, branch_name: Option<String>) {
+		let branch_compare =
+			branch_name.as_ref().and_then(|branch| {
+				sync::branch_compare_upstream(
+					&self.repo.borrow(),
+					branch.as_str(),
+				)
+				.ok()
+			});
+
+		self.list.set_branch(branch_name, branch_compare);
+		self.list.set_head(sync::get_head(&self.repo.borrow()).ok());
+		self.branch_outdated = false;
+	}
+
 	///
 	pub fn update_git(
 		&mut self,
 		ev: AsyncGitNotification,
 	) -> Result<()> {
+		if matches!(
+			ev,
+			AsyncGitNotification::Fetch
+				| AsyncGitNotification::Pull
+				| AsyncGitNotification::Push
+		) {
+			self.branch_outdated = true;
+		}
+
+		if matches!(ev, AsyncGitNotification::AuthorCommits) {
+			self.author_commits_found()?;
+		}
+
+		if matches!(ev, AsyncGitNotification::LineStats) {
+			self.commit_details.update_line_stats();
+		}
+
 		if self.visible {
 			match ev {
 				AsyncGitNotification::CommitFiles
-				| AsyncGitNotification::Log => self.update()?,
+				| AsyncGitNotification::Log
+				| AsyncGitNotification::Fetch
+				| AsyncGitNotification::Pull
+				| AsyncGitNotification::Push => self.update()?,
 				AsyncGitNotification::Tags => {
 					if let Some(tags) = self.git_tags.last()? {
 						self.list.set_tags(tags);
@@ -150,11 +361,52 @@ impl Revlog {
 		let commits = sync::get_commits_info(
 			&self.repo.borrow(),
 			&self.git_log.get_slice(want_min, SLICE_SIZE)?,
-			self.list.current_size().0.into(),
+			self.message_width(),
 		);
 
 		if let Ok(commits) = commits {
 			self.list.items().set_items(want_min, commits);
+			self.cache_signatures();
+		}
+
+		self.fetch_pinned();
+
+		Ok(())
+	}
+
+	fn message_width(&self) -> usize {
+		let mut width = usize::from(self.list.current_size().0);
+		if self.list.wrap_message() {
+			width *= 2;
+		}
+		width
+	}
+
+	/// pinned commits that do not exist (anymore) are not shown
+	fn fetch_pinned(&mut self) {
+		let width = self.message_width();
+		let repo = self.repo.borrow();
+
+		let pinned = self
+			.pins
+			.ids()
+			.into_iter()
+			.filter_map(|id| {
+				sync::get_commits_info(&repo, &[id], width).ok()
+			})
+			.flatten()
+			.collect();
+
+		self.list.set_pinned(pinned);
+	}
+
+	/// pins the selected commit to the top of the log,
+	/// unpins it if it already is
+	fn toggle_pin(&mut self) -> Result<()> {
+		if let Some(id) = self.selected_commit() {
+			self.pins.toggle(id);
+			self.pins.save(&self.repo.borrow())?;
+			self.fetch_pinned();
 		}
 
 		Ok(())
@@ -164,8 +416,216 @@ impl Revlog {
 		self.list.selected_entry().map(|e| e.id)
 	}
 
-	fn copy_commit_hash(&self) -> Result<()> {
-		self.list.copy_entry_hash()?;
+	/// selects `id` as soon as the log walk reaches it
+	pub fn select_commit_once_loaded(&mut self, id: CommitId) {
+		self.pending_selection = Some(id);
+	}
+
+	/// commit to restore in the next session
+	pub fn last_selected_commit(&self) -> Option<CommitId> {
+		self.pending_selection.or_else(|| self.selected_commit())
+	}
+
+	fn select_pending(&mut self) -> Result<()> {
+		if let Some(id) = self.pending_selection {
+			if let Some(position) = self.git_log.position(id)? {
+				self.list.select_entry(position);
+				self.pending_selection = None;
+			} else if !self.git_log.is_pending() {
+				// not part of the log (anymore)
+				self.pending_selection = None;
+			}
+		}
+
+		Ok(())
+	}
+
+	fn cache_signatures(&mut self) {
+		if self.list.signatures_shown() {
+			let repo = self.repo.borrow();
+			self.list.cache_signatures(|id| {
+				sync::commit_signed(&repo, id).ok()
+			});
+		}
+	}
+
+	fn selected_parent(&self) -> Option<CommitId> {
+		self.list
+			.selected_entry()
+			.and_then(|e| e.parents.first().copied())
+	}
+
+	fn copy_commit_hash(&self, full: bool) -> Result<()> {
+		self.list.copy_entry_hash(full)?;
+		Ok(())
+	}
+
+	/// copies the subjects of the range between the two marked
+	/// commits, newest first unless configured otherwise
+	fn copy_range_subjects(&mut self) -> Result<()> {
+		let marked = self.list.marked();
+		let subjects = sync::range_subjects(
+			&self.repo.borrow(),
+			(marked[0], marked[1]),
+			self.subjects_oldest_first,
+		)?;
+		crate::clipboard::copy_string(&subjects.join("\n"))?;
+
+		self.list.set_status(Some(
+			strings::log_copied_range_subjects(subjects.len()),
+		));
+
+		Ok(())
+	}
+
+	fn copy_tree_hash(&self) -> Result<()> {
+		if let Some(id) = self.selected_commit() {
+			let tree = sync::commit_tree_id(&self.repo.borrow(), id)?;
+			crate::clipboard::copy_string(&tree)?;
+		}
+
+		Ok(())
+	}
+
+	/// uses `remote` for commit links from now on
+	/// and copies the link of the selected commit
+	pub fn set_url_remote(&mut self, remote: String) -> Result<()> {
+		self.url_remote = Some(remote);
+		self.copy_commit_markdown_link()
+	}
+
+	fn copy_commit_markdown_link(&self) -> Result<()> {
+		if let Some(e) = self.list.selected_entry() {
+			let repo = self.repo.borrow();
+
+			let needs_remote = self
+				.commit_url_template
+				.as_ref()
+				.map_or(true, |template| template.contains("{repo}"));
+
+			// let the user pick if there is no `origin` to default to
+			if needs_remote
+				&& self.url_remote.is_none()
+				&& sync::get_remotes(&repo)?.len() > 1
+				&& sync::get_default_remote(&repo).is_err()
+			{
+				self.queue.push(InternalEvent::SelectUrlRemote);
+				return Ok(());
+			}
+
+			let website = if needs_remote {
+				sync::remote_url(&repo, self.url_remote.as_deref())?
+			} else {
+				None
+			};
+
+			let link =
+				if let Some(template) = &self.commit_url_template {
+					format!(
+						"[{}]({})",
+						e.hash_short,
+						sync::fill_commit_url_template(
+							template,
+							website.as_deref(),
+							e.id
+						)?
+					)
+				} else {
+					website.map_or_else(
+						|| e.hash_short.to_string(),
+						|url| {
+							format!(
+								"[{}]({}/commit/{})",
+								e.hash_short,
+								url,
+								e.id.to_string()
+							)
+						},
+					)
+				};
+
+			crate::clipboard::copy_string(&link)?;
+		}
+
+		Ok(())
+	}
+
+	/// the full timestamp, the list only shows relative times
+	fn copy_commit_date(&self) -> Result<()> {
+		if let Some(e) = self.list.selected_entry() {
+			crate::clipboard::copy_string(&time_to_string(
+				e.time.timestamp(),
+				false,
+			))?;
+		}
+
+		Ok(())
+	}
+
+	/// copies the `git revert` command of the selected commit,
+	/// only staging it if `gitui.revertCommandNoCommit` is set
+	fn copy_revert_command(&self) -> Result<()> {
+		if let Some(e) = self.list.selected_entry() {
+			crate::clipboard::copy_string(&strings::revert_command(
+				&e.id.to_string(),
+				self.revert_no_commit,
+				e.parents.len() > 1,
+			))?;
+		}
+
+		Ok(())
+	}
+
+	/// copies the short hashes of all loaded commits by the author
+	/// of the selected one (newest first)
+	fn copy_author_hashes(&mut self) -> Result<()> {
+		let author = match self.list.selected_entry() {
+			Some(e) => e.author.to_string(),
+			None => return Ok(()),
+		};
+
+		// looking up every loaded commit takes a while
+		self.async_author_commits.spawn(AsyncAuthorCommitsJob::new(
+			self.repo.borrow().clone(),
+			self.git_log.get_slice(0, usize::MAX)?,
+			&author,
+		));
+		self.list.set_status(Some(
+			strings::log_finding_author_commits(&author),
+		));
+
+		Ok(())
+	}
+
+	/// copies the hashes found by `copy_author_hashes`
+	fn author_commits_found(&mut self) -> Result<()> {
+		let job = match self.async_author_commits.take_last() {
+			Some(job) => job,
+			None => return Ok(()),
+		};
+		let ids = match job.result() {
+			Some(ids) => ids?,
+			None => return Ok(()),
+		};
+		let author = job.author();
+
+		if ids.is_empty() {
+			self.list.set_status(Some(
+				strings::log_no_author_commits().to_string(),
+			));
+		} else {
+			let hashes = ids
+				.iter()
+				.map(CommitId::get_short_string)
+				.collect::<Vec<_>>()
+				.join("\n");
+			crate::clipboard::copy_string(&hashes)?;
+
+			self.list.set_status(Some(
+				strings::log_copied_author_hashes(ids.len(), author),
+			));
+		}
+
 		Ok(())
 	}
 
@@ -192,10 +652,382 @@ impl Revlog {
 		}
 	}
 
+	/// selects the commit at the 1-based `position`
+	/// (clamped to the loaded commits)
+	pub fn goto_position(&mut self, position: usize) -> Result<()> {
+		self.list.select_entry(
+			position.saturating_sub(1).min(self.list.selection_max()),
+		);
+		self.update()
+	}
+
+	/// selects the closest older (or newer) tagged commit of the log,
+	/// wraps around if `gitui.logTagNavigationWrap` is set
+	fn goto_tagged(&mut self, older: bool) -> Result<()> {
+		let mut tagged = Vec::new();
+		if let Some(tags) = self.list.tags() {
+			for id in tags.keys() {
+				if let Some(position) = self.git_log.position(*id)? {
+					tagged.push(position);
+				}
+			}
+		}
+
+		match self.list.tagged_position(&tagged, older, self.tag_wrap)
+		{
+			Some(position) => {
+				self.list.select_entry(position);
+				self.update()?;
+			}
+			None => {
+				self.list.set_status(Some(
+					strings::log_no_tagged_commit().to_string(),
+				));
+			}
+		}
+
+		Ok(())
+	}
+
+	/// selects the merge-base of `HEAD` and `branch` (at `id`)
+	/// and shows it in the status line. lifts the commit limit
+	/// if the merge-base is not loaded
+	pub fn goto_merge_base(
+		&mut self,
+		branch: &str,
+		id: CommitId,
+	) -> Result<()> {
+		let base = {
+			let repo = self.repo.borrow();
+			sync::merge_base(&repo, sync::get_head(&repo)?, id)?
+		};
+
+		match base {
+			Some(base) => {
+				if self.git_log.max_commits().is_some()
+					&& !self.git_log.is_pending()
+					&& self.git_log.position(base)?.is_none()
+				{
+					self.git_log.set_max_commits(None)?;
+					self.list.clear();
+					self.update_title();
+				}
+
+				self.pending_selection = Some(base);
+				self.update()?;
+				self.list.set_status(Some(strings::log_merge_base(
+					branch,
+					&base.get_short_string(),
+				)));
+			}
+			None => {
+				self.list.set_status(Some(
+					strings::log_no_merge_base(branch),
+				));
+			}
+		}
+
+		Ok(())
+	}
+
+	/// selects the nearest tag of the selected commit
+	/// and shows its `git describe` in the status line
+	fn goto_described_tag(&mut self) -> Result<()> {
+		if let Some(id) = self.selected_commit() {
+			let desc =
+				sync::describe_commit(&self.repo.borrow(), id)?;
+			match desc {
+				Some(desc) => {
+					self.pending_selection = Some(desc.tag_commit);
+					self.select_pending()?;
+					self.list.set_status(Some(
+						strings::log_describe(&desc.description),
+					));
+				}
+				None => {
+					self.list.set_status(Some(
+						strings::log_describe_no_tag().to_string(),
+					));
+				}
+			}
+		}
+
+		Ok(())
+	}
+
 	fn revert_commit(&self) -> Result<()> {
 		if let Some(c) = self.selected_commit() {
-			sync::revert_commit(&self.repo.borrow(), c)?;
-			self.queue.push(InternalEvent::TabSwitchStatus);
+			if self.confirm_revert {
+				self.queue.push(InternalEvent::ConfirmAction(
+					Action::RevertCommit(c),
+				));
+			} else {
+				sync::revert_commit(&self.repo.borrow(), c)?;
+				self.queue.push(InternalEvent::TabSwitchStatus);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn toggle_first_parent(&mut self) -> Result<()> {
+		self.git_log
+			.set_first_parent(!self.git_log.first_parent())?;
+		self.list.clear();
+		self.update_title();
+		self.update()
+	}
+
+	/// the order is saved right away
+	fn toggle_topo_order(&mut self) -> Result<()> {
+		self.columns.borrow_mut().toggle_topo_order();
+		self.git_log
+			.set_topo_order(self.columns.borrow().topo_order())?;
+		self.list.clear();
+		self.update_title();
+		self.update()?;
+		self.columns.borrow().save()
+	}
+
+	/// the option is saved right away
+	fn toggle_no_merges(&mut self) -> Result<()> {
+		self.columns.borrow_mut().toggle_no_merges();
+		self.git_log
+			.set_no_merges(self.columns.borrow().no_merges())?;
+		self.list.clear();
+		self.update_title();
+		self.update()?;
+		self.columns.borrow().save()
+	}
+
+	/// first annotated tag of the selected commit
+	fn selected_annotated_tag(&self) -> Option<(CommitId, Tag)> {
+		let id = self.selected_commit()?;
+		self.selected_commit_tags(&Some(id))?
+			.into_iter()
+			.find(|tag| tag.annotation.is_some())
+			.map(|tag| (id, tag))
+	}
+
+	/// refetches the tags even if they were just loaded
+	pub fn reload_tags(&mut self) -> Result<()> {
+		self.git_tags.request(Duration::from_secs(0), true)?;
+		Ok(())
+	}
+
+	/// unlike the regular updates this discards everything cached
+	fn reload(&mut self) -> Result<()> {
+		self.git_log.reset()?;
+		self.list.clear_cached();
+		self.branch_name = cached::BranchName::new(self.repo.clone());
+		self.git_tags.request(Duration::from_secs(3), true)?;
+		self.update()
+	}
+
+	fn toggle_max_commits(&mut self) -> Result<()> {
+		let max_commits = if self.git_log.max_commits().is_some() {
+			None
+		} else {
+			self.max_commits
+		};
+
+		self.git_log.set_max_commits(max_commits)?;
+		self.list.clear();
+		self.update_title();
+		self.update()
+	}
+
+	fn toggle_since(&mut self) -> Result<()> {
+		let since = if self.git_log.since().is_some() {
+			None
+		} else {
+			// huge settings just load everything
+			Some(chrono::Local::now().timestamp().saturating_sub(
+				self.since_days.saturating_mul(24 * 60 * 60),
+			))
+		};
+
+		self.git_log.set_since(since)?;
+		self.list.clear();
+		self.update_title();
+		self.update()
+	}
+
+	fn toggle_hide_empty(&mut self) -> Result<()> {
+		self.hide_empty = !self.hide_empty;
+		self.apply_filter()
+	}
+
+	fn toggle_only_conflicted(&mut self) -> Result<()> {
+		self.only_conflicted = !self.only_conflicted;
+		self.apply_filter()
+	}
+
+	/// off, weekend only, weekdays only
+	fn toggle_weekend(&mut self) -> Result<()> {
+		self.weekend = match self.weekend {
+			None => Some(true),
+			Some(true) => Some(false),
+			Some(false) => None,
+		};
+		self.apply_filter()
+	}
+
+	/// combines all enabled commit filters and restarts the log walk
+	fn apply_filter(&mut self) -> Result<()> {
+		let repo_path = self.repo.borrow().clone();
+
+		let non_empty = if self.hide_empty {
+			Some(sync::non_empty_commits(repo_path))
+		} else {
+			None
+		};
+		let conflicted = if self.only_conflicted {
+			Some(Arc::clone(&self.conflicted_filter))
+		} else {
+			None
+		};
+		let weekend = self.weekend.map(|weekend| {
+			sync::commit_time_matches(move |time| {
+				is_weekend(time) == weekend
+			})
+		});
+
+		let mut filters: Vec<LogWalkerFilter> =
+			vec![non_empty, conflicted, weekend]
+				.into_iter()
+				.flatten()
+				.collect();
+
+		let filter: Option<LogWalkerFilter> = if filters.len() > 1 {
+			Some(Arc::new(Box::new(move |repo, id| {
+				for filter in &filters {
+					if !filter(repo, id)? {
+						return Ok(false);
+					}
+				}
+				Ok(true)
+			})))
+		} else {
+			filters.pop()
+		};
+
+		self.git_log.set_filter(filter)?;
+		self.list.clear();
+		self.update_title();
+		self.update()
+	}
+
+	/// the columns shown by the list changed
+	pub fn columns_changed(&mut self) {
+		self.cache_signatures();
+	}
+
+	fn update_title(&mut self) {
+		let mut modes = Vec::new();
+
+		if self.git_log.first_parent() {
+			modes.push(strings::log_mode_first_parent().to_string());
+		}
+
+		if self.git_log.topo_order() {
+			modes.push(strings::log_mode_topo_order().to_string());
+		}
+
+		if self.git_log.no_merges() {
+			modes.push(strings::log_mode_no_merges().to_string());
+		}
+
+		if let Some(max_commits) = self.git_log.max_commits() {
+			modes.push(strings::log_mode_max_commits(max_commits));
+		}
+
+		if self.hide_empty {
+			modes.push(strings::log_mode_hide_empty().to_string());
+		}
+
+		if self.only_conflicted {
+			modes.push(
+				strings::log_mode_only_conflicted().to_string(),
+			);
+		}
+
+		match self.weekend {
+			Some(true) => {
+				modes.push(strings::log_mode_weekend().to_string());
+			}
+			Some(false) => {
+				modes.push(strings::log_mode_weekdays().to_string());
+			}
+			None => (),
+		}
+
+		if let Some(since) = self.git_log.since() {
+			modes.push(strings::log_mode_since(&time_to_string(
+				since, true,
+			)));
+		}
+
+		self.list.set_title(
+			strings::log_title_with_modes(&self.key_config, &modes)
+				.into(),
+		);
+	}
+
+	fn amend_message(&self) -> Result<()> {
+		let repo = self.repo.borrow();
+
+		if self.selected_commit() != Some(sync::get_head(&repo)?) {
+			anyhow::bail!("only the HEAD commit can be amended");
+		}
+
+		if !sync::status::get_status(&repo, StatusType::Both, None)?
+			.is_empty()
+		{
+			anyhow::bail!(
+				"commit or stash your changes before amending the message"
+			);
+		}
+
+		self.queue.push(InternalEvent::OpenAmendCommit);
+
+		Ok(())
+	}
+
+	fn details_event(&mut self, ev: Event) -> Result<EventState> {
+		if self.commit_details.event(ev)?.is_consumed() {
+			return Ok(EventState::Consumed);
+		}
+
+		if let Event::Key(k) = ev {
+			if k == self.key_config.keys.focus_left {
+				self.set_details_focus(false);
+				return Ok(EventState::Consumed);
+			} else if k == self.key_config.keys.focus_right {
+				self.inspect_commit();
+				return Ok(EventState::Consumed);
+			}
+		}
+
+		Ok(EventState::NotConsumed)
+	}
+
+	fn set_details_focus(&mut self, focus: bool) {
+		self.commit_details.focus(focus);
+		self.list.focus(!focus);
+	}
+
+	/// moves focus between the log and the inline details,
+	/// showing the details first if needed
+	fn toggle_details_focus(&mut self) -> Result<()> {
+		if self.commit_details.focused() {
+			self.set_details_focus(false);
+		} else {
+			if !self.commit_details.is_visible() {
+				self.commit_details.toggle_visible()?;
+				self.update()?;
+			}
+			self.set_details_focus(true);
 		}
 
 		Ok(())
@@ -242,9 +1074,14 @@ impl DrawableComponent for Revlog {
 }
 
 impl Component for Revlog {
+	#[allow(clippy::too_many_lines)]
 	fn event(&mut self, ev: Event) -> Result<EventState> {
 		if self.visible {
-			let event_used = self.list.event(ev)?;
+			let event_used = if self.list.focused() {
+				self.list.event(ev)?
+			} else {
+				self.details_event(ev)?
+			};
 
 			if event_used.is_consumed() {
 				self.update()?;
@@ -252,10 +1089,256 @@ impl Component for Revlog {
 			} else if let Event::Key(k) = ev {
 				if k == self.key_config.keys.enter {
 					self.commit_details.toggle_visible()?;
+					if !self.commit_details.is_visible() {
+						self.set_details_focus(false);
+					}
 					self.update()?;
 					return Ok(EventState::Consumed);
+				} else if k
+					== self.key_config.keys.log_toggle_details_focus
+				{
+					self.toggle_details_focus()?;
+					return Ok(EventState::Consumed);
 				} else if k == self.key_config.keys.copy {
-					self.copy_commit_hash()?;
+					self.copy_commit_hash(false)?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_copy_full_hash
+				{
+					self.copy_commit_hash(true)?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_copy_tree_hash
+					&& self.selected_commit().is_some()
+				{
+					try_or_popup!(
+						self,
+						"copy error:",
+						self.copy_tree_hash()
+					);
+					return Ok(EventState::Consumed);
+				} else if k
+					== self.key_config.keys.log_copy_markdown_link
+					&& self.selected_commit().is_some()
+				{
+					try_or_popup!(
+						self,
+						"copy error:",
+						self.copy_commit_markdown_link()
+					);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_copy_date
+					&& self.selected_commit().is_some()
+				{
+					try_or_popup!(
+						self,
+						"copy error:",
+						self.copy_commit_date()
+					);
+					return Ok(EventState::Consumed);
+				} else if k
+					== self.key_config.keys.log_copy_revert_command
+					&& self.selected_commit().is_some()
+				{
+					try_or_popup!(
+						self,
+						"copy error:",
+						self.copy_revert_command()
+					);
+					return Ok(EventState::Consumed);
+				} else if k
+					== self.key_config.keys.log_copy_author_hashes
+					&& self.selected_commit().is_some()
+				{
+					try_or_popup!(
+						self,
+						"copy error:",
+						self.copy_author_hashes()
+					);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.commit_amend
+					&& self.selected_commit().is_some()
+				{
+					try_or_popup!(
+						self,
+						"amend error:",
+						self.amend_message()
+					);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_max_commits
+					&& self.max_commits.is_some()
+				{
+					self.toggle_max_commits()?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_since {
+					self.toggle_since()?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_goto_parent {
+					if let Some(parent) = self.selected_parent() {
+						try_or_popup!(
+							self,
+							"parent error:",
+							self.select_commit(parent)
+						);
+						self.update()?;
+					}
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_goto_child {
+					if self.list.select_child() {
+						self.update()?;
+					}
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_author_rank {
+					self.list.toggle_author_rank();
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_columns {
+					self.queue.push(InternalEvent::OpenLogColumns);
+					return Ok(EventState::Consumed);
+				} else if k
+					== self.key_config.keys.log_command_palette
+				{
+					self.queue
+						.push(InternalEvent::OpenLogCommandPalette);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_goto_position
+				{
+					self.queue.push(InternalEvent::OpenGotoCommit);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_recent_commits
+				{
+					self.queue.push(InternalEvent::OpenRecentCommits);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_describe {
+					try_or_popup!(
+						self,
+						"describe error:",
+						self.goto_described_tag()
+					);
+					self.update()?;
+					return Ok(EventState::Consumed);
+				} else if k
+					== self.key_config.keys.log_author_initials
+				{
+					try_or_popup!(
+						self,
+						"saving log columns failed:",
+						self.list.toggle_initials()
+					);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_signatures {
+					try_or_popup!(
+						self,
+						"saving log columns failed:",
+						self.list.toggle_signatures()
+					);
+					self.cache_signatures();
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_commit_delay {
+					try_or_popup!(
+						self,
+						"saving log columns failed:",
+						self.list.toggle_commit_delay()
+					);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_reload {
+					self.reload()?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_inline_tags {
+					self.list.toggle_tags();
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_emoji {
+					self.list.toggle_emoji();
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_wrap_message {
+					try_or_popup!(
+						self,
+						"saving log columns failed:",
+						self.list.toggle_wrap_message()
+					);
+					// messages are fetched cut to the width
+					self.list.clear();
+					self.update()?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_oneline {
+					try_or_popup!(
+						self,
+						"saving log columns failed:",
+						self.list.toggle_oneline()
+					);
+					self.list.clear();
+					self.update()?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_full_hash {
+					try_or_popup!(
+						self,
+						"saving log columns failed:",
+						self.list.toggle_full_hash()
+					);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_merge_base {
+					self.queue
+						.push(InternalEvent::SelectMergeBaseBranch);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_no_merges {
+					try_or_popup!(
+						self,
+						"hiding merges failed:",
+						self.toggle_no_merges()
+					);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_topo_order {
+					try_or_popup!(
+						self,
+						"changing log order failed:",
+						self.toggle_topo_order()
+					);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_squash_preview
+					&& self.list.marked_count() == 2
+				{
+					let marked = self.list.marked();
+					self.queue.push(
+						InternalEvent::OpenSquashPreview((
+							marked[0], marked[1],
+						)),
+					);
+					return Ok(EventState::Consumed);
+				} else if k
+					== self.key_config.keys.log_copy_range_subjects
+					&& self.list.marked_count() == 2
+				{
+					try_or_popup!(
+						self,
+						"copy error:",
+						self.copy_range_subjects()
+					);
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_next_tagged {
+					self.goto_tagged(true)?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_prev_tagged {
+					self.goto_tagged(false)?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_first_parent {
+					self.toggle_first_parent()?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_hide_empty {
+					self.toggle_hide_empty()?;
+					return Ok(EventState::Consumed);
+				} else if k
+					== self.key_config.keys.log_only_conflicted
+				{
+					self.toggle_only_conflicted()?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_weekend {
+					self.toggle_weekend()?;
+					return Ok(EventState::Consumed);
+				} else if k == self.key_config.keys.log_pin_commit
+					&& self.selected_commit().is_some()
+				{
+					try_or_popup!(
+						self,
+						"pin error:",
+						self.toggle_pin()
+					);
 					return Ok(EventState::Consumed);
 				} else if k == self.key_config.keys.push {
 					self.queue.push(InternalEvent::PushTags);
@@ -269,10 +1352,34 @@ impl Component for Revlog {
 							Ok(EventState::Consumed)
 						},
 					);
+				} else if k == self.key_config.keys.log_edit_tag {
+					return self.selected_annotated_tag().map_or(
+						Ok(EventState::NotConsumed),
+						|(id, tag)| {
+							self.queue.push(InternalEvent::EditTag(
+								id,
+								tag.name,
+								tag.annotation.unwrap_or_default(),
+							));
+							Ok(EventState::Consumed)
+						},
+					);
+				} else if k
+					== self.key_config.keys.log_create_worktree
+				{
+					return self.selected_commit().map_or(
+						Ok(EventState::NotConsumed),
+						|id| {
+							self.queue.push(
+								InternalEvent::CreateWorktree(id),
+							);
+							Ok(EventState::Consumed)
+						},
+					);
 				} else if k == self.key_config.keys.focus_right
 					&& self.commit_details.is_visible()
 				{
-					self.inspect_commit();
+					self.set_details_focus(true);
 					return Ok(EventState::Consumed);
 				} else if k == self.key_config.keys.select_branch {
 					self.queue.push(InternalEvent::SelectBranch);
@@ -337,85 +1444,572 @@ impl Component for Revlog {
 		Ok(EventState::NotConsumed)
 	}
 
+	#[allow(clippy::too_many_lines)]
 	fn commands(
 		&self,
 		out: &mut Vec<CommandInfo>,
 		force_all: bool,
 	) -> CommandBlocking {
 		if self.visible || force_all {
-			self.list.commands(out, force_all);
+			if self.list.focused() {
+				self.list.commands(out, force_all);
+			} else {
+				self.commit_details.commands(out, force_all);
+			}
 		}
 
-		out.push(CommandInfo::new(
-			strings::commands::log_details_toggle(&self.key_config),
-			true,
-			self.visible,
-		));
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_details_toggle(
+					&self.key_config,
+				),
+				true,
+				self.visible,
+			)
+			.key(self.key_config.keys.enter),
+		);
 
 		out.push(CommandInfo::new(
-			strings::commands::commit_details_open(&self.key_config),
+			strings::commands::log_details_focus(
+				&self.key_config,
+				self.commit_details.focused(),
+			),
 			true,
 			(self.visible && self.commit_details.is_visible())
 				|| force_all,
 		));
 
-		out.push(CommandInfo::new(
-			strings::commands::open_branch_select_popup(
-				&self.key_config,
-			),
-			true,
-			self.visible || force_all,
-		));
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_details_focus(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_toggle_details_focus),
+		);
 
-		out.push(CommandInfo::new(
-			strings::commands::compare_with_head(&self.key_config),
-			self.list.marked_count() == 1,
-			(self.visible && self.list.marked_count() <= 1)
-				|| force_all,
-		));
+		out.push(
+			CommandInfo::new(
+				strings::commands::commit_details_open(
+					&self.key_config,
+				),
+				true,
+				(self.visible && self.commit_details.focused())
+					|| force_all,
+			)
+			.key(self.key_config.keys.focus_right),
+		);
 
-		out.push(CommandInfo::new(
-			strings::commands::compare_commits(&self.key_config),
-			true,
-			(self.visible && self.list.marked_count() == 2)
-				|| force_all,
-		));
+		out.push(
+			CommandInfo::new(
+				strings::commands::open_branch_select_popup(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.select_branch),
+		);
 
-		out.push(CommandInfo::new(
-			strings::commands::copy_hash(&self.key_config),
-			self.selected_commit().is_some(),
-			self.visible || force_all,
-		));
+		out.push(
+			CommandInfo::new(
+				strings::commands::compare_with_head(
+					&self.key_config,
+				),
+				self.list.marked_count() == 1,
+				(self.visible && self.list.marked_count() <= 1)
+					|| force_all,
+			)
+			.key(self.key_config.keys.compare_commits),
+		);
 
-		out.push(CommandInfo::new(
-			strings::commands::log_tag_commit(&self.key_config),
-			self.selected_commit().is_some(),
-			self.visible || force_all,
-		));
+		out.push(
+			CommandInfo::new(
+				strings::commands::compare_commits(&self.key_config),
+				true,
+				(self.visible && self.list.marked_count() == 2)
+					|| force_all,
+			)
+			.key(self.key_config.keys.compare_commits),
+		);
 
-		out.push(CommandInfo::new(
-			strings::commands::open_tags_popup(&self.key_config),
-			true,
-			self.visible || force_all,
-		));
+		out.push(
+			CommandInfo::new(
+				strings::commands::copy_hash(&self.key_config),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.copy),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::copy_full_hash(&self.key_config),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_copy_full_hash),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::copy_tree_hash(&self.key_config),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_copy_tree_hash),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::copy_markdown_link(
+					&self.key_config,
+				),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_copy_markdown_link),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::copy_commit_date(&self.key_config),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_copy_date),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::copy_revert_command(
+					&self.key_config,
+				),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_copy_revert_command),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::copy_author_hashes(
+					&self.key_config,
+				),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_copy_author_hashes),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_amend_message(
+					&self.key_config,
+				),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.commit_amend),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_max_commits(
+					&self.key_config,
+					self.git_log.max_commits().is_some(),
+				),
+				true,
+				(self.visible && self.max_commits.is_some())
+					|| force_all,
+			)
+			.key(self.key_config.keys.log_max_commits),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_since(
+					&self.key_config,
+					self.git_log.since().is_some(),
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_since),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_goto_parent(&self.key_config),
+				self.selected_parent().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_goto_parent),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_goto_child(&self.key_config),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_goto_child),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_inline_tags(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_inline_tags),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_emoji(&self.key_config),
+				cfg!(feature = "ghemoji"),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_emoji),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_create_worktree(
+					&self.key_config,
+				),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_create_worktree),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_edit_tag(&self.key_config),
+				self.selected_annotated_tag().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_edit_tag),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_squash_preview(
+					&self.key_config,
+				),
+				true,
+				(self.visible && self.list.marked_count() == 2)
+					|| force_all,
+			)
+			.key(self.key_config.keys.log_squash_preview),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_copy_range_subjects(
+					&self.key_config,
+				),
+				true,
+				(self.visible && self.list.marked_count() == 2)
+					|| force_all,
+			)
+			.key(self.key_config.keys.log_copy_range_subjects),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_wrap_message(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_wrap_message),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_oneline(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_oneline),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_full_hash(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_full_hash),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_topo_order(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_topo_order),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_no_merges(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_no_merges),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_goto_merge_base(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_merge_base),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_next_tagged(&self.key_config),
+				self.list.tags().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_next_tagged),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_prev_tagged(&self.key_config),
+				self.list.tags().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_prev_tagged),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_author_rank(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_author_rank),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_columns_popup(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_columns),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_recent_commits(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_recent_commits),
+		);
 
 		out.push(CommandInfo::new(
-			strings::commands::push_tags(&self.key_config),
+			strings::commands::log_command_palette(&self.key_config),
 			true,
 			self.visible || force_all,
 		));
 
-		out.push(CommandInfo::new(
-			strings::commands::inspect_file_tree(&self.key_config),
-			self.selected_commit().is_some(),
-			self.visible || force_all,
-		));
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_goto_position(
+					&self.key_config,
+				),
+				self.list.selection_max() > 0,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_goto_position),
+		);
 
-		out.push(CommandInfo::new(
-			strings::commands::revert_commit(&self.key_config),
-			self.selected_commit().is_some(),
-			self.visible || force_all,
-		));
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_describe(&self.key_config),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_describe),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_author_initials(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_author_initials),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_signatures(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_signatures),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_commit_delay(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_commit_delay),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_reload(&self.key_config),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_reload),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_first_parent(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_first_parent),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_hide_empty(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_hide_empty),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_only_conflicted(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_only_conflicted),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_weekend(
+					&self.key_config,
+				),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_weekend),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_toggle_pin(
+					&self.key_config,
+					self.selected_commit()
+						.map_or(false, |id| self.pins.is_pinned(id)),
+				),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_pin_commit),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::log_tag_commit(&self.key_config),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.log_tag_commit),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::open_tags_popup(&self.key_config),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.tags),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::push_tags(&self.key_config),
+				true,
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.push),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::inspect_file_tree(
+					&self.key_config,
+				),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.open_file_tree),
+		);
+
+		out.push(
+			CommandInfo::new(
+				strings::commands::revert_commit(&self.key_config),
+				self.selected_commit().is_some(),
+				self.visible || force_all,
+			)
+			.key(self.key_config.keys.status_reset_item),
+		);
 
 		visibility_blocking(self)
 	}
@@ -427,6 +2021,12 @@ impl Component for Revlog {
 	fn hide(&mut self) {
 		self.visible = false;
 		self.git_log.set_background();
+
+		// the log can change while hidden, so follow the selected
+		// commit instead of its index when showing the tab again
+		if self.pending_selection.is_none() {
+			self.pending_selection = self.selected_commit();
+		}
 	}
 
 	fn show(&mut self) -> Result<()> {
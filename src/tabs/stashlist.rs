@@ -5,6 +5,7 @@ use crate::{
 		InspectCommitOpen,
 	},
 	keys::SharedKeyConfig,
+	log_columns::SharedLogColumns,
 	queue::{Action, InternalEvent, Queue, StackablePopupOpen},
 	strings,
 	ui::style::SharedTheme,
@@ -35,6 +36,8 @@ impl StashList {
 				&strings::stashlist_title(&key_config),
 				theme,
 				key_config.clone(),
+				// the configured columns are for the log only
+				SharedLogColumns::default(),
 			),
 			queue: queue.clone(),
 			key_config,
@@ -0,0 +1,205 @@
+//! minimal support for ANSI escape sequences in text:
+//! stripping them or interpreting the SGR (color/style) ones
+
+use std::borrow::Cow;
+use tui::{
+	style::{Color, Modifier, Style},
+	text::Span,
+};
+
+const ESC: char = '\x1b';
+
+/// removes all ANSI escape sequences from `text`
+pub fn strip_ansi(text: Cow<'_, str>) -> Cow<'_, str> {
+	if !text.contains(ESC) {
+		return text;
+	}
+
+	let mut res = String::with_capacity(text.len());
+	for part in parse(&text) {
+		if let Part::Text(txt) = part {
+			res.push_str(txt);
+		}
+	}
+
+	Cow::Owned(res)
+}
+
+/// turns `line` into spans styled according to its SGR sequences
+/// on top of `base`. `state` carries the style changes from
+/// one line to the next and is updated by this call.
+pub fn ansi_spans(
+	line: &str,
+	base: Style,
+	state: &mut Style,
+) -> Vec<Span<'static>> {
+	let mut spans = Vec::new();
+
+	for part in parse(line) {
+		match part {
+			Part::Text(txt) => spans.push(Span::styled(
+				txt.to_string(),
+				base.patch(*state),
+			)),
+			Part::Sgr(params) => apply_sgr(state, params),
+		}
+	}
+
+	spans
+}
+
+enum Part<'a> {
+	Text(&'a str),
+	/// parameters of a `CSI ... m` sequence
+	Sgr(&'a str),
+}
+
+/// splits `text` into plain text and SGR sequences,
+/// all other escape sequences are dropped
+fn parse(text: &str) -> Vec<Part<'_>> {
+	let mut parts = Vec::new();
+	let mut start = 0;
+	let mut chars = text.char_indices().peekable();
+
+	while let Some((idx, c)) = chars.next() {
+		if c != ESC {
+			continue;
+		}
+
+		if idx > start {
+			parts.push(Part::Text(&text[start..idx]));
+		}
+		start = idx + c.len_utf8();
+
+		if let Some(&(_, '[')) = chars.peek() {
+			chars.next();
+			let params_start = start + 1;
+			start = text.len();
+			for (end, c) in chars.by_ref() {
+				if ('\x40'..='\x7e').contains(&c) {
+					if c == 'm' {
+						parts.push(Part::Sgr(
+							&text[params_start..end],
+						));
+					}
+					start = end + c.len_utf8();
+					break;
+				}
+			}
+		}
+	}
+
+	if start < text.len() {
+		parts.push(Part::Text(&text[start..]));
+	}
+
+	parts
+}
+
+fn apply_sgr(style: &mut Style, params: &str) {
+	let mut params =
+		params.split(';').map(|p| p.parse::<u8>().unwrap_or(0));
+
+	while let Some(param) = params.next() {
+		match param {
+			0 => *style = Style::default(),
+			1 => style.add_modifier.insert(Modifier::BOLD),
+			2 => style.add_modifier.insert(Modifier::DIM),
+			3 => style.add_modifier.insert(Modifier::ITALIC),
+			4 => style.add_modifier.insert(Modifier::UNDERLINED),
+			7 => style.add_modifier.insert(Modifier::REVERSED),
+			22 => style
+				.add_modifier
+				.remove(Modifier::BOLD | Modifier::DIM),
+			23 => style.add_modifier.remove(Modifier::ITALIC),
+			24 => style.add_modifier.remove(Modifier::UNDERLINED),
+			27 => style.add_modifier.remove(Modifier::REVERSED),
+			30..=37 => style.fg = Some(basic_color(param - 30)),
+			38 => style.fg = extended_color(&mut params),
+			39 => style.fg = None,
+			40..=47 => style.bg = Some(basic_color(param - 40)),
+			48 => style.bg = extended_color(&mut params),
+			49 => style.bg = None,
+			90..=97 => style.fg = Some(bright_color(param - 90)),
+			100..=107 => style.bg = Some(bright_color(param - 100)),
+			_ => (),
+		}
+	}
+}
+
+/// `5;n` (indexed) or `2;r;g;b` (true color)
+fn extended_color(
+	params: &mut impl Iterator<Item = u8>,
+) -> Option<Color> {
+	match params.next()? {
+		5 => params.next().map(Color::Indexed),
+		2 => Some(Color::Rgb(
+			params.next()?,
+			params.next()?,
+			params.next()?,
+		)),
+		_ => None,
+	}
+}
+
+const fn basic_color(idx: u8) -> Color {
+	match idx {
+		0 => Color::Black,
+		1 => Color::Red,
+		2 => Color::Green,
+		3 => Color::Yellow,
+		4 => Color::Blue,
+		5 => Color::Magenta,
+		6 => Color::Cyan,
+		_ => Color::Gray,
+	}
+}
+
+const fn bright_color(idx: u8) -> Color {
+	match idx {
+		0 => Color::DarkGray,
+		1 => Color::LightRed,
+		2 => Color::LightGreen,
+		3 => Color::LightYellow,
+		4 => Color::LightBlue,
+		5 => Color::LightMagenta,
+		6 => Color::LightCyan,
+		_ => Color::White,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_strip() {
+		assert_eq!(strip_ansi(Cow::from("plain")), "plain");
+		assert_eq!(
+			strip_ansi(Cow::from("a \x1b[1;31mred\x1b[0m b")),
+			"a red b"
+		);
+		assert_eq!(strip_ansi(Cow::from("\x1b[2Kx\x1b")), "x");
+		assert_eq!(strip_ansi(Cow::from("open \x1b[31")), "open ");
+	}
+
+	#[test]
+	fn test_spans() {
+		let base = Style::default().add_modifier(Modifier::BOLD);
+		let mut state = Style::default();
+
+		let spans = ansi_spans("a\x1b[31mb", base, &mut state);
+		assert_eq!(spans.len(), 2);
+		assert_eq!(spans[0].content, "a");
+		assert_eq!(spans[0].style, base);
+		assert_eq!(spans[1].content, "b");
+		assert_eq!(spans[1].style, base.fg(Color::Red));
+
+		// style carries over to the next line
+		let spans =
+			ansi_spans("c\x1b[38;5;42;22md\x1b[0m", base, &mut state);
+		assert_eq!(spans[0].style, base.fg(Color::Red));
+		assert_eq!(spans[1].style, base.fg(Color::Indexed(42)));
+		assert_eq!(state, Style::default());
+	}
+}
@@ -1,3 +1,4 @@
+mod ansi;
 mod reflow;
 mod scrollbar;
 mod scrolllist;
@@ -5,8 +6,9 @@ mod stateful_paragraph;
 pub mod style;
 mod syntax_text;
 
+pub use ansi::{ansi_spans, strip_ansi};
 use filetreelist::MoveSelection;
-pub use scrollbar::draw_scrollbar;
+pub use scrollbar::{draw_scrollbar, draw_scrollbar_pending};
 pub use scrolllist::{draw_list, draw_list_block};
 pub use stateful_paragraph::{
 	ParagraphState, ScrollPos, StatefulParagraph,
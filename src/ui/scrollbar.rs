@@ -14,7 +14,8 @@ use tui::{
 ///
 struct Scrollbar {
 	max: u16,
-	pos: u16,
+	/// `None` only draws the track (position not known yet)
+	pos: Option<u16>,
 	style_bar: Style,
 	style_pos: Style,
 }
@@ -23,7 +24,7 @@ impl Scrollbar {
 	fn new(max: usize, pos: usize) -> Self {
 		Self {
 			max: u16::try_from(max).unwrap_or_default(),
-			pos: u16::try_from(pos).unwrap_or_default(),
+			pos: Some(u16::try_from(pos).unwrap_or_default()),
 			style_pos: Style::default(),
 			style_bar: Style::default(),
 		}
@@ -58,7 +59,12 @@ impl Widget for Scrollbar {
 			buf.set_string(right, y, DOUBLE_VERTICAL, self.style_bar);
 		}
 
-		let progress = f32::from(self.pos) / f32::from(self.max);
+		let pos = match self.pos {
+			Some(pos) => pos,
+			None => return,
+		};
+
+		let progress = f32::from(pos) / f32::from(self.max);
 		let progress = if progress > 1.0 { 1.0 } else { progress };
 		let pos = f32::from(bar_height) * progress;
 
@@ -80,3 +86,11 @@ pub fn draw_scrollbar<B: Backend>(
 	widget.style_pos = theme.scroll_bar_pos();
 	f.render_widget(widget, r);
 }
+
+/// scrollbar without a position marker, for content that is still
+/// loading and whose final length is unknown
+pub fn draw_scrollbar_pending<B: Backend>(f: &mut Frame<B>, r: Rect) {
+	let mut widget = Scrollbar::new(1, 0);
+	widget.pos = None;
+	f.render_widget(widget, r);
+}
@@ -48,6 +48,16 @@ pub struct Theme {
 	#[serde(with = "Color")]
 	commit_author: Color,
 	#[serde(with = "Color")]
+	commit_head: Color,
+	/// background of the selected commit in the log,
+	/// `selection_bg` if not set
+	#[serde(default)]
+	commit_selection_bg: Option<Color>,
+	/// text of the selected commit in the log,
+	/// keeps the colors of each column if not set
+	#[serde(default)]
+	commit_selection_fg: Option<Color>,
+	#[serde(with = "Color")]
 	danger_fg: Color,
 	#[serde(with = "Color")]
 	push_gauge_bg: Color,
@@ -179,6 +189,24 @@ impl Theme {
 		}
 	}
 
+	pub fn diff_stat(&self, added: bool) -> Style {
+		if added {
+			Style::default().fg(self.diff_line_add)
+		} else {
+			Style::default().fg(self.diff_line_delete)
+		}
+	}
+
+	/// changed words within added or deleted lines (see `diff_line`)
+	pub fn diff_word(
+		&self,
+		typ: DiffLineType,
+		selected: bool,
+	) -> Style {
+		self.diff_line(typ, selected)
+			.add_modifier(Modifier::REVERSED)
+	}
+
 	pub fn diff_hunk_marker(&self, selected: bool) -> Style {
 		if selected {
 			Style::default().bg(self.selection_bg)
@@ -246,6 +274,23 @@ impl Theme {
 		style
 	}
 
+	/// `signed` is `None` while unknown
+	pub fn commit_signature(
+		&self,
+		signed: Option<bool>,
+		selected: bool,
+	) -> Style {
+		let style = match signed {
+			Some(true) => Style::default()
+				.fg(self.diff_line_add)
+				.add_modifier(Modifier::BOLD),
+			Some(false) => Style::default().fg(self.danger_fg),
+			None => Style::default().fg(self.disabled_fg),
+		};
+
+		self.apply_select(style, selected)
+	}
+
 	pub fn commit_time(&self, selected: bool) -> Style {
 		self.apply_select(
 			Style::default().fg(self.commit_time),
@@ -260,6 +305,81 @@ impl Theme {
 		)
 	}
 
+	pub fn commit_head(&self, selected: bool) -> Style {
+		self.apply_select(
+			Style::default()
+				.fg(self.commit_head)
+				.add_modifier(Modifier::BOLD),
+			selected,
+		)
+	}
+
+	/// `style` of a part of the selected commit in the log.
+	/// colors that would vanish on the background fall back
+	/// to `command_fg`
+	pub fn commit_selected(&self, style: Style) -> Style {
+		let bg =
+			self.commit_selection_bg.unwrap_or(self.selection_bg);
+		let fg = self
+			.commit_selection_fg
+			.or(style.fg)
+			.filter(|fg| *fg != bg)
+			.unwrap_or(if self.command_fg == bg {
+				Color::Reset
+			} else {
+				self.command_fg
+			});
+
+		style.fg(fg).bg(bg)
+	}
+
+	/// deterministic color per `author` out of the theme colors
+	pub fn author_avatar(
+		&self,
+		author: &str,
+		selected: bool,
+	) -> Style {
+		let palette = [
+			self.commit_hash,
+			self.commit_time,
+			self.commit_author,
+			self.diff_line_add,
+			self.diff_line_delete,
+			self.diff_file_moved,
+			self.diff_file_modified,
+			self.selection_bg,
+		];
+
+		let hash = author.bytes().fold(0_usize, |hash, b| {
+			hash.wrapping_mul(31).wrapping_add(usize::from(b))
+		});
+
+		self.apply_select(
+			Style::default()
+				.fg(palette[hash % palette.len()])
+				.add_modifier(Modifier::BOLD),
+			selected,
+		)
+	}
+
+	/// `age` in seconds a line was changed before the blamed commit
+	pub fn blame_line_age(&self, age: i64) -> Style {
+		const DAY: i64 = 24 * 60 * 60;
+
+		match age {
+			i64::MIN..=DAY => Style::default()
+				.fg(self.diff_line_add)
+				.add_modifier(Modifier::BOLD),
+			age if age <= 30 * DAY => {
+				Style::default().fg(self.diff_line_add)
+			}
+			age if age <= 365 * DAY => {
+				Style::default().fg(self.diff_file_modified)
+			}
+			_ => Style::default().fg(self.disabled_fg),
+		}
+	}
+
 	pub fn commit_hash_in_blame(
 		&self,
 		is_blamed_commit: bool,
@@ -337,6 +457,9 @@ impl Default for Theme {
 			commit_hash: Color::Magenta,
 			commit_time: Color::LightCyan,
 			commit_author: Color::Green,
+			commit_head: Color::LightYellow,
+			commit_selection_bg: None,
+			commit_selection_fg: None,
 			danger_fg: Color::Red,
 			push_gauge_bg: Color::Blue,
 			push_gauge_fg: Color::Reset,